@@ -0,0 +1,130 @@
+//! Attestor-signed claims gating age- or region-restricted content.
+//!
+//! The admin approves attestor ed25519 keys. An attestor signs an
+//! `Attestation` that an address holds a platform-defined attribute until a
+//! ledger; the signed message is the XDR of `(contract_address, attestation)`.
+//! Fans submit it once and the claim is stored per (address, attribute).
+//! Content a creator flags with a required attribute can only be bought or
+//! accessed by holders of a valid, unexpired claim for it.
+//!
+//! Approval records the ledger it happened at, and a claim only counts while
+//! its attestor is approved and was submitted at or after that approval, so
+//! revoking an attestor invalidates its claims even if it is approved again.
+
+use crate::DataKey;
+use soroban_sdk::{
+    contracterror, contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+
+/// Attestation error codes, continuing the numbering of [`crate::Error`].
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttestationError {
+    /// Code 52 – attestation is signed by a key the admin has not approved.
+    AttestorNotApproved = 52,
+    /// Code 53 – attestation submitted at or after its `expires_at` ledger.
+    AttestationExpired = 53,
+    /// Code 54 – content requires an attribute the address holds no valid claim for.
+    AttestationRequired = 54,
+}
+
+/// Statement signed by an attestor that `subject` holds `attribute`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub subject: Address,
+    /// Platform-defined attribute code (e.g. age over 18, region).
+    pub attribute: u32,
+    /// Ledger sequence from which the claim no longer holds (exclusive).
+    pub expires_at: u64,
+}
+
+/// Stored attestation for an (address, attribute).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeClaim {
+    /// Key of the attestor that signed the claim.
+    pub attestor: BytesN<32>,
+    pub expires_at: u64,
+    /// Ledger sequence the claim was submitted at.
+    pub submitted_at: u64,
+}
+
+/// Bytes an attestor signs for `attestation`.
+pub fn message(env: &Env, attestation: &Attestation) -> Bytes {
+    (env.current_contract_address(), attestation.clone()).to_xdr(env)
+}
+
+/// Ledger `attestor` was last approved at, if currently approved.
+pub fn approved_at(env: &Env, attestor: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Attestor(attestor.clone()))
+}
+
+/// Verify `signature` by an approved `attestor` and store the claim.
+///
+/// An invalid signature traps in the host's ed25519 verification.
+pub fn submit(
+    env: &Env,
+    attestation: &Attestation,
+    attestor: &BytesN<32>,
+    signature: &BytesN<64>,
+) -> AttributeClaim {
+    if approved_at(env, attestor).is_none() {
+        panic_with_error!(env, AttestationError::AttestorNotApproved);
+    }
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    if current_seq >= attestation.expires_at {
+        panic_with_error!(env, AttestationError::AttestationExpired);
+    }
+    env.crypto()
+        .ed25519_verify(attestor, &message(env, attestation), signature);
+
+    let claim = AttributeClaim {
+        attestor: attestor.clone(),
+        expires_at: attestation.expires_at,
+        submitted_at: current_seq,
+    };
+    env.storage().instance().set(
+        &DataKey::Claim(attestation.subject.clone(), attestation.attribute),
+        &claim,
+    );
+    claim
+}
+
+pub fn claim(env: &Env, holder: &Address, attribute: u32) -> Option<AttributeClaim> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Claim(holder.clone(), attribute))
+}
+
+/// Whether `holder` has an unexpired claim from a still-approved attestor.
+pub fn holds(env: &Env, holder: &Address, attribute: u32) -> bool {
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    claim(env, holder, attribute).is_some_and(|claim| {
+        claim.expires_at > current_seq
+            && approved_at(env, &claim.attestor).is_some_and(|at| claim.submitted_at >= at)
+    })
+}
+
+pub fn required(env: &Env, creator: &Address, content_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequiredAttribute(creator.clone(), content_id))
+}
+
+/// Whether `holder` meets the attribute requirement of (creator, content_id).
+pub fn is_allowed(env: &Env, holder: &Address, creator: &Address, content_id: u64) -> bool {
+    match required(env, creator, content_id) {
+        Some(attribute) => holds(env, holder, attribute),
+        None => true,
+    }
+}
+
+/// Reject `holder` if (creator, content_id) requires an attribute they lack.
+pub fn require_allowed(env: &Env, holder: &Address, creator: &Address, content_id: u64) {
+    if !is_allowed(env, holder, creator, content_id) {
+        panic_with_error!(env, AttestationError::AttestationRequired);
+    }
+}
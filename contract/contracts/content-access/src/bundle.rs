@@ -0,0 +1,147 @@
+//! Multi-item bundles sold at a single price.
+//!
+//! Buying a bundle writes an `Access` record for every member and a
+//! `BundleAccess` record for the bundle itself; the latter lets `has_access`
+//! honour content the creator adds to the bundle after the purchase.
+//!
+//! Direct purchases credited towards a bundle are traded in: their receipts
+//! are removed, so they can be neither refunded nor credited again.
+//!
+//! Limited editions never join a bundle: bundle access is not numbered, so
+//! it would let buyers past the supply cap.
+
+use crate::{supply, DataKey, Error, Purchase, Receipt};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+/// Upper bound on the number of content items in a bundle.
+pub const MAX_BUNDLE_SIZE: u32 = 50;
+
+/// A creator-defined set of content items sold together.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bundle {
+    pub creator: Address,
+    /// Member content IDs, all owned by `creator`.
+    pub content_ids: Vec<u64>,
+    /// Full bundle price before credit for already-owned members.
+    pub price: i128,
+    /// Ledgers of access granted from purchase, or `None` for lifetime access.
+    pub duration_ledgers: Option<u64>,
+}
+
+pub fn get(env: &Env, bundle_id: u64) -> Bundle {
+    env.storage()
+        .instance()
+        .get(&DataKey::Bundle(bundle_id))
+        .unwrap_or_else(|| panic_with_error!(env, Error::BundleNotFound))
+}
+
+/// Reject empty, oversized or duplicate-member bundles, and bundles with a
+/// limited-edition member.
+pub fn require_valid_members(env: &Env, creator: &Address, content_ids: &Vec<u64>) {
+    if content_ids.is_empty() || content_ids.len() > MAX_BUNDLE_SIZE {
+        panic_with_error!(env, Error::InvalidBundle);
+    }
+    for (i, id) in content_ids.iter().enumerate() {
+        if content_ids.iter().skip(i + 1).any(|other| other == id)
+            || supply::cap(env, creator, id).is_some()
+        {
+            panic_with_error!(env, Error::InvalidBundle);
+        }
+    }
+}
+
+/// Whether (creator, content_id) belongs to any bundle.
+pub fn is_member(env: &Env, creator: &Address, content_id: u64) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::ContentBundles(creator.clone(), content_id))
+}
+
+/// Record that `bundle_id` contains (creator, content_id).
+pub fn index_member(env: &Env, creator: &Address, content_id: u64, bundle_id: u64) {
+    let key = DataKey::ContentBundles(creator.clone(), content_id);
+    let mut ids: Vec<u64> = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    ids.push_back(bundle_id);
+    env.storage().instance().set(&key, &ids);
+}
+
+/// Latest expiry among the buyer's bundle purchases covering (creator, content_id).
+pub fn access_expiry(
+    env: &Env,
+    buyer: &Address,
+    creator: &Address,
+    content_id: u64,
+) -> Option<u64> {
+    let bundle_ids: Vec<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ContentBundles(creator.clone(), content_id))?;
+    bundle_ids
+        .iter()
+        .filter_map(|bundle_id| {
+            env.storage()
+                .instance()
+                .get::<DataKey, Purchase>(&DataKey::BundleAccess(buyer.clone(), bundle_id))
+                .map(|p| p.expiry)
+        })
+        .max()
+}
+
+/// Amount paid for the buyer's direct purchase of `content_id`, if it is
+/// still valid and can be credited.
+fn member_credit(
+    env: &Env,
+    buyer: &Address,
+    creator: &Address,
+    content_id: u64,
+    now: u64,
+) -> Option<i128> {
+    let valid = env
+        .storage()
+        .instance()
+        .get::<DataKey, Purchase>(&DataKey::Access(buyer.clone(), creator.clone(), content_id))
+        .is_some_and(|p| p.expiry > now);
+    if !valid {
+        return None;
+    }
+    env.storage()
+        .instance()
+        .get::<DataKey, Receipt>(&DataKey::Receipt(
+            buyer.clone(),
+            creator.clone(),
+            content_id,
+        ))
+        .map(|r| r.amount)
+}
+
+/// Sum paid for members the buyer still holds valid direct purchases of.
+pub fn credit(env: &Env, buyer: &Address, bundle: &Bundle, now: u64) -> i128 {
+    bundle
+        .content_ids
+        .iter()
+        .filter_map(|content_id| member_credit(env, buyer, &bundle.creator, content_id, now))
+        .sum()
+}
+
+/// Trade in the purchases counted by [`credit`] by removing their receipts.
+pub fn redeem_credit(env: &Env, buyer: &Address, bundle: &Bundle, now: u64) {
+    for content_id in bundle.content_ids.iter() {
+        if member_credit(env, buyer, &bundle.creator, content_id, now).is_some() {
+            env.storage().instance().remove(&DataKey::Receipt(
+                buyer.clone(),
+                bundle.creator.clone(),
+                content_id,
+            ));
+        }
+    }
+}
+
+/// Bundle price after credit, never below zero.
+pub fn quote(env: &Env, buyer: &Address, bundle: &Bundle, now: u64) -> i128 {
+    core::cmp::max(bundle.price - credit(env, buyer, bundle, now), 0)
+}
@@ -0,0 +1,34 @@
+//! Batch checkout of several content items in one transaction.
+
+use crate::Error;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+/// Upper bound on items per `unlock_many` call, keeping the batch within
+/// per-transaction resource limits.
+pub const MAX_CART_SIZE: u32 = 20;
+
+/// One line of a cart: which access option of which content item to buy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CartItem {
+    pub creator: Address,
+    pub content_id: u64,
+    /// Index into the item's access options (see `get_access_options`).
+    pub option: u32,
+}
+
+/// Reject empty, oversized or duplicate-item carts.
+pub fn require_valid_items(env: &Env, items: &Vec<CartItem>) {
+    if items.is_empty() || items.len() > MAX_CART_SIZE {
+        panic_with_error!(env, Error::InvalidCart);
+    }
+    for (i, item) in items.iter().enumerate() {
+        if items
+            .iter()
+            .skip(i + 1)
+            .any(|other| other.creator == item.creator && other.content_id == item.content_id)
+        {
+            panic_with_error!(env, Error::InvalidCart);
+        }
+    }
+}
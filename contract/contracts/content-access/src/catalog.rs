@@ -0,0 +1,73 @@
+//! Per-creator index of registered content, in registration order.
+//!
+//! The index lives in persistent storage with one key per content ID, so a
+//! large catalog never grows the contract instance and a page reads only the
+//! IDs it returns. Catalog entries are never removed, only deactivated.
+
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Once an index entry's remaining TTL drops below this many ledgers (about
+/// 30 days), it is refreshed back up to `CATALOG_TTL_EXTEND_TO`.
+const CATALOG_TTL_THRESHOLD: u32 = 518_400;
+/// About 180 days. See [`CATALOG_TTL_THRESHOLD`].
+const CATALOG_TTL_EXTEND_TO: u32 = 3_110_400;
+
+/// Persistent catalog index keys, nested under `DataKey::Catalog`.
+#[contracttype]
+#[derive(Clone)]
+pub enum CatalogKey {
+    /// Number of content items a creator registered: creator -> u32
+    Len(Address),
+    /// Content ID registered at an index: (creator, index) -> u64
+    Item(Address, u32),
+}
+
+fn get<V: TryFromVal<Env, Val>>(env: &Env, key: CatalogKey) -> Option<V> {
+    let key = DataKey::Catalog(key);
+    let value = env.storage().persistent().get(&key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, CATALOG_TTL_THRESHOLD, CATALOG_TTL_EXTEND_TO);
+    }
+    value
+}
+
+fn set<V: IntoVal<Env, Val>>(env: &Env, key: CatalogKey, value: &V) {
+    let key = DataKey::Catalog(key);
+    env.storage().persistent().set(&key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, CATALOG_TTL_THRESHOLD, CATALOG_TTL_EXTEND_TO);
+}
+
+/// Number of content items `creator` registered.
+pub fn len(env: &Env, creator: &Address) -> u32 {
+    get(env, CatalogKey::Len(creator.clone())).unwrap_or(0)
+}
+
+/// Append `content_id` to `creator`'s catalog index.
+pub fn add(env: &Env, creator: &Address, content_id: u64) {
+    let index = len(env, creator);
+    set(env, CatalogKey::Item(creator.clone(), index), &content_id);
+    set(env, CatalogKey::Len(creator.clone()), &(index + 1));
+}
+
+/// Page `[cursor, cursor + limit)` of `creator`'s content IDs, with the
+/// cursor of the next page (0 at the end).
+pub fn page(env: &Env, creator: &Address, cursor: u32, limit: u32) -> (Vec<u64>, u32) {
+    let mut page = Vec::new(env);
+    let len = len(env, creator);
+    if cursor >= len || limit == 0 {
+        return (page, 0);
+    }
+    let end = core::cmp::min(cursor + limit, len);
+    for i in cursor..end {
+        if let Some(content_id) = get(env, CatalogKey::Item(creator.clone(), i)) {
+            page.push_back(content_id);
+        }
+    }
+    let next_cursor = if end < len { end } else { 0 };
+    (page, next_cursor)
+}
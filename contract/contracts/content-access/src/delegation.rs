@@ -0,0 +1,91 @@
+//! Sharing a purchase with a limited number of other addresses.
+//!
+//! A delegate's access is resolved through the owner's own purchase on every
+//! check, so it ends as soon as that purchase expires or is refunded. When
+//! the owner's purchase is refunded, cancelled or resold, the delegation
+//! links are dropped as well, so the delegates can accept new delegations.
+//! Links to an owner whose purchase has lapsed are dropped the next time one
+//! of the delegates is offered a new delegation.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+/// Upper bound on the per-content share limit a creator may configure.
+pub const MAX_SHARE_LIMIT: u32 = 10;
+
+/// Ledgers an owner must wait between delegation changes (~1 day at 5s ledgers).
+pub const DELEGATION_COOLDOWN_LEDGERS: u64 = 17_280;
+
+pub fn share_limit(env: &Env, creator: &Address, content_id: u64) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ShareLimit(creator.clone(), content_id))
+        .unwrap_or(0)
+}
+
+pub fn delegates(env: &Env, owner: &Address, creator: &Address, content_id: u64) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Delegates(
+            owner.clone(),
+            creator.clone(),
+            content_id,
+        ))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_delegates(
+    env: &Env,
+    owner: &Address,
+    creator: &Address,
+    content_id: u64,
+    list: &Vec<Address>,
+) {
+    env.storage().instance().set(
+        &DataKey::Delegates(owner.clone(), creator.clone(), content_id),
+        list,
+    );
+}
+
+/// Owner whose purchase `delegate` currently shares, if any.
+pub fn owner_of(
+    env: &Env,
+    delegate: &Address,
+    creator: &Address,
+    content_id: u64,
+) -> Option<Address> {
+    env.storage().instance().get(&DataKey::DelegatedFrom(
+        delegate.clone(),
+        creator.clone(),
+        content_id,
+    ))
+}
+
+/// Reject a change made within the cooldown of the owner's previous change,
+/// then start a new cooldown.
+pub fn start_cooldown(env: &Env, owner: &Address, creator: &Address, content_id: u64) {
+    let key = DataKey::DelegationChangedAt(owner.clone(), creator.clone(), content_id);
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    if let Some(changed_at) = env.storage().instance().get::<DataKey, u64>(&key) {
+        if current_seq < changed_at.saturating_add(DELEGATION_COOLDOWN_LEDGERS) {
+            panic_with_error!(env, Error::DelegationCooldown);
+        }
+    }
+    env.storage().instance().set(&key, &current_seq);
+}
+
+/// Drop every delegation of `owner`'s purchase, e.g. once it changes hands.
+pub fn clear(env: &Env, owner: &Address, creator: &Address, content_id: u64) {
+    for delegate in delegates(env, owner, creator, content_id).iter() {
+        env.storage().instance().remove(&DataKey::DelegatedFrom(
+            delegate,
+            creator.clone(),
+            content_id,
+        ));
+    }
+    env.storage().instance().remove(&DataKey::Delegates(
+        owner.clone(),
+        creator.clone(),
+        content_id,
+    ));
+}
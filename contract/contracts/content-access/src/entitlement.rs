@@ -0,0 +1,42 @@
+//! Subscription-based access to content the creator includes for subscribers.
+
+use crate::DataKey;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface content-access expects from the subscription contract.
+#[contractclient(name = "SubscriptionClient")]
+pub trait Subscription {
+    /// Whether `fan` holds an active subscription to `creator`.
+    fn is_subscriber(env: Env, fan: Address, creator: Address) -> bool;
+    /// Tier of the plan behind `fan`'s active subscription, or `None`.
+    fn subscriber_tier(env: Env, fan: Address, creator: Address) -> Option<u32>;
+}
+
+/// Whether `fan`'s subscription to `creator` unlocks `content_id`.
+///
+/// False when the content is not included for subscribers or no
+/// subscription contract is configured.
+pub fn is_entitled(env: &Env, fan: &Address, creator: &Address, content_id: u64) -> bool {
+    let min_tier: u32 = match env
+        .storage()
+        .instance()
+        .get(&DataKey::SubscriberAccess(creator.clone(), content_id))
+    {
+        Some(min_tier) => min_tier,
+        None => return false,
+    };
+    let contract: Address = match env.storage().instance().get(&DataKey::SubscriptionContract) {
+        Some(contract) => contract,
+        None => return false,
+    };
+
+    let subscription = SubscriptionClient::new(env, &contract);
+    if min_tier == 0 {
+        subscription.is_subscriber(fan, creator)
+    } else {
+        // `subscriber_tier` is `None` without an active subscription.
+        subscription
+            .subscriber_tier(fan, creator)
+            .is_some_and(|tier| tier >= min_tier)
+    }
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address};
+use crate::{AccessOption, Bundle, ModerationStatus};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,6 +15,129 @@ pub struct ContentPriceSetEvent {
     pub price: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentRegisteredEvent {
+    pub creator: Address,
+    pub content_id: u64,
+    pub price: i128,
+    pub metadata_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentStatusEvent {
+    pub creator: Address,
+    pub content_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOptionsSetEvent {
+    pub creator: Address,
+    pub content_id: u64,
+    pub options: Vec<AccessOption>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleCreatedEvent {
+    pub bundle_id: u64,
+    pub bundle: Bundle,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleUnlockedEvent {
+    pub bundle_id: u64,
+    /// Full bundle price.
+    pub price: i128,
+    /// Credit applied for already-owned members.
+    pub credit: i128,
+    /// Amount paid by the buyer (`price - credit`, floored at zero).
+    pub charged: i128,
+    /// Platform fee taken out of `charged`; the creator receives the rest.
+    pub fee: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriberAccessEvent {
+    pub creator: Address,
+    pub content_id: u64,
+    /// Minimum plan tier as passed to `include_for_subscribers` (`None` admits
+    /// any subscriber); always `None` on `subscriber_access_removed`.
+    pub min_tier: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherRedeemedEvent {
+    pub content_id: u64,
+    pub nonce: u64,
+    /// Voucher price charged to the buyer.
+    pub price: i128,
+    /// Platform fee taken out of `price`.
+    pub fee: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentGiftedEvent {
+    pub creator: Address,
+    pub content_id: u64,
+    /// Price charged to the payer.
+    pub price: i128,
+    /// Platform fee taken out of `price`.
+    pub fee: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentModeratedEvent {
+    pub content_id: u64,
+    pub previous: ModerationStatus,
+    pub status: ModerationStatus,
+    pub reason: u32,
+    /// Admin who took the action.
+    pub moderator: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseScheduledEvent {
+    pub content_id: u64,
+    /// Release ledger before this change, if one was set.
+    pub previous: Option<u64>,
+    pub release_ledger: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreOrdersSettledEvent {
+    pub content_id: u64,
+    /// Number of pre-orders paid out.
+    pub count: u32,
+    /// Total escrowed for those pre-orders.
+    pub gross: i128,
+    /// Portion of `gross` paid to the fee recipient.
+    pub fee: i128,
+    /// Pre-orders still escrowed after this batch.
+    pub remaining: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseRefundedEvent {
+    pub content_id: u64,
+    /// Creator or admin who funded the refund.
+    pub refunder: Address,
+    /// Total returned to the buyer.
+    pub amount: i128,
+    /// Portion of `amount` covering the platform fee (0 if not refunded).
+    pub fee_refunded: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MaxPriceSetEvent {
@@ -33,3 +157,14 @@ pub struct AdminTransferredEvent {
     pub old_admin: Address,
     pub new_admin: Address,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessResoldEvent {
+    pub creator: Address,
+    pub content_id: u64,
+    /// Listing price paid by the buyer.
+    pub price: i128,
+    /// Portion of `price` paid to the creator; the seller receives the rest.
+    pub royalty: i128,
+}
@@ -0,0 +1,272 @@
+//! Purchase history indexes: the direct and bundle purchases each buyer
+//! holds, and the holders of each content item.
+//!
+//! Entries follow the `Access` records: added when a purchase is recorded,
+//! moved on resale and dropped on refund or pre-order cancellation. Expiry
+//! is read from the `Access` record, so renewals show up and expired
+//! purchases stay listed with their past expiry.
+//!
+//! Both indexes live in persistent storage with one key per entry, so they
+//! can grow without bloating the contract instance and a page reads only
+//! the entries it returns. A removed entry is filled by the list's last one,
+//! keeping each list dense. Entries are kept alive on every read and write;
+//! an entry archived anyway is skipped rather than failing the call.
+//!
+//! Bundle grants are indexed for the members a bundle has when it is bought.
+//! Content added to the bundle later is covered through `BundleAccess` but is
+//! not listed.
+
+use crate::{DataKey, Purchase};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Once a history entry's remaining TTL drops below this many ledgers (about
+/// 30 days), it is refreshed back up to `HISTORY_TTL_EXTEND_TO`.
+const HISTORY_TTL_THRESHOLD: u32 = 518_400;
+/// About 180 days. See [`HISTORY_TTL_THRESHOLD`].
+const HISTORY_TTL_EXTEND_TO: u32 = 3_110_400;
+
+/// One purchase in a buyer's or a content item's history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseRecord {
+    pub buyer: Address,
+    pub creator: Address,
+    pub content_id: u64,
+    /// Ledger the access ends at; `u64::MAX` for permanent purchases.
+    pub expiry: u64,
+    /// Amount paid for the latest purchase; 0 when granted by a bundle.
+    pub price: i128,
+}
+
+/// Persistent history keys, nested under `DataKey::History`.
+#[contracttype]
+#[derive(Clone)]
+pub enum HistoryKey {
+    /// Number of purchases a buyer holds: buyer -> u32
+    BuyerLen(Address),
+    /// A buyer's purchase at an index: (buyer, index) -> (creator, content_id)
+    BuyerItem(Address, u32),
+    /// Number of holders of a content item: (creator, content_id) -> u32
+    ContentLen(Address, u64),
+    /// A holder at an index: (creator, content_id, index) -> buyer
+    ContentItem(Address, u64, u32),
+    /// Price and list positions of one purchase: (buyer, creator, content_id) -> Entry
+    Entry(Address, Address, u64),
+}
+
+/// Price paid for one indexed purchase and where it sits in both lists.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub price: i128,
+    pub buyer_index: u32,
+    pub content_index: u32,
+}
+
+fn get<V: TryFromVal<Env, Val>>(env: &Env, key: HistoryKey) -> Option<V> {
+    let key = DataKey::History(key);
+    let value = env.storage().persistent().get(&key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, HISTORY_TTL_THRESHOLD, HISTORY_TTL_EXTEND_TO);
+    }
+    value
+}
+
+fn set<V: IntoVal<Env, Val>>(env: &Env, key: HistoryKey, value: &V) {
+    let key = DataKey::History(key);
+    env.storage().persistent().set(&key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, HISTORY_TTL_THRESHOLD, HISTORY_TTL_EXTEND_TO);
+}
+
+fn unset(env: &Env, key: HistoryKey) {
+    env.storage().persistent().remove(&DataKey::History(key));
+}
+
+fn entry(env: &Env, holder: &Address, creator: &Address, content_id: u64) -> Option<Entry> {
+    get(
+        env,
+        HistoryKey::Entry(holder.clone(), creator.clone(), content_id),
+    )
+}
+
+/// Number of purchases `buyer` holds.
+pub fn purchase_count(env: &Env, buyer: &Address) -> u32 {
+    get(env, HistoryKey::BuyerLen(buyer.clone())).unwrap_or(0)
+}
+
+/// (creator, content_id) of `buyer`'s purchase at `index`, if still stored.
+pub fn purchase_at(env: &Env, buyer: &Address, index: u32) -> Option<(Address, u64)> {
+    get(env, HistoryKey::BuyerItem(buyer.clone(), index))
+}
+
+/// Number of holders of (creator, content_id).
+pub fn buyer_count(env: &Env, creator: &Address, content_id: u64) -> u32 {
+    get(env, HistoryKey::ContentLen(creator.clone(), content_id)).unwrap_or(0)
+}
+
+/// Holder of (creator, content_id) at `index`, if still stored.
+pub fn buyer_at(env: &Env, creator: &Address, content_id: u64, index: u32) -> Option<Address> {
+    get(
+        env,
+        HistoryKey::ContentItem(creator.clone(), content_id, index),
+    )
+}
+
+/// Index `holder`'s purchase of (creator, content_id) and note what it cost.
+pub fn add(env: &Env, holder: &Address, creator: &Address, content_id: u64, price: i128) {
+    let key = HistoryKey::Entry(holder.clone(), creator.clone(), content_id);
+    if let Some(mut existing) = entry(env, holder, creator, content_id) {
+        existing.price = price;
+        set(env, key, &existing);
+        return;
+    }
+
+    let buyer_index = purchase_count(env, holder);
+    set(
+        env,
+        HistoryKey::BuyerItem(holder.clone(), buyer_index),
+        &(creator.clone(), content_id),
+    );
+    set(
+        env,
+        HistoryKey::BuyerLen(holder.clone()),
+        &(buyer_index + 1),
+    );
+
+    let content_index = buyer_count(env, creator, content_id);
+    set(
+        env,
+        HistoryKey::ContentItem(creator.clone(), content_id, content_index),
+        holder,
+    );
+    set(
+        env,
+        HistoryKey::ContentLen(creator.clone(), content_id),
+        &(content_index + 1),
+    );
+
+    set(
+        env,
+        key,
+        &Entry {
+            price,
+            buyer_index,
+            content_index,
+        },
+    );
+}
+
+/// Drop `holder`'s purchase of (creator, content_id) from both indexes.
+pub fn remove(env: &Env, holder: &Address, creator: &Address, content_id: u64) {
+    let Some(removed) = entry(env, holder, creator, content_id) else {
+        return;
+    };
+    unset(
+        env,
+        HistoryKey::Entry(holder.clone(), creator.clone(), content_id),
+    );
+
+    // Move the buyer's last purchase into the freed slot.
+    let last = purchase_count(env, holder) - 1;
+    if removed.buyer_index != last {
+        if let Some((moved_creator, moved_id)) = purchase_at(env, holder, last) {
+            set(
+                env,
+                HistoryKey::BuyerItem(holder.clone(), removed.buyer_index),
+                &(moved_creator.clone(), moved_id),
+            );
+            if let Some(mut moved) = entry(env, holder, &moved_creator, moved_id) {
+                moved.buyer_index = removed.buyer_index;
+                set(
+                    env,
+                    HistoryKey::Entry(holder.clone(), moved_creator, moved_id),
+                    &moved,
+                );
+            }
+        }
+    }
+    unset(env, HistoryKey::BuyerItem(holder.clone(), last));
+    set_len(env, HistoryKey::BuyerLen(holder.clone()), last);
+
+    // Move the item's last holder into the freed slot.
+    let last = buyer_count(env, creator, content_id) - 1;
+    if removed.content_index != last {
+        if let Some(moved_holder) = buyer_at(env, creator, content_id, last) {
+            set(
+                env,
+                HistoryKey::ContentItem(creator.clone(), content_id, removed.content_index),
+                &moved_holder,
+            );
+            if let Some(mut moved) = entry(env, &moved_holder, creator, content_id) {
+                moved.content_index = removed.content_index;
+                set(
+                    env,
+                    HistoryKey::Entry(moved_holder, creator.clone(), content_id),
+                    &moved,
+                );
+            }
+        }
+    }
+    unset(
+        env,
+        HistoryKey::ContentItem(creator.clone(), content_id, last),
+    );
+    set_len(
+        env,
+        HistoryKey::ContentLen(creator.clone(), content_id),
+        last,
+    );
+}
+
+fn set_len(env: &Env, key: HistoryKey, len: u32) {
+    if len == 0 {
+        unset(env, key);
+    } else {
+        set(env, key, &len);
+    }
+}
+
+/// Current state of `buyer`'s indexed purchase of (creator, content_id).
+pub fn record(env: &Env, buyer: &Address, creator: &Address, content_id: u64) -> PurchaseRecord {
+    let expiry = env
+        .storage()
+        .instance()
+        .get::<DataKey, Purchase>(&DataKey::Access(buyer.clone(), creator.clone(), content_id))
+        .map_or(0, |p| p.expiry);
+    let price = entry(env, buyer, creator, content_id).map_or(0, |e| e.price);
+    PurchaseRecord {
+        buyer: buyer.clone(),
+        creator: creator.clone(),
+        content_id,
+        expiry,
+        price,
+    }
+}
+
+/// Page `[cursor, cursor + limit)` of a list of `len` entries read by `at`,
+/// with the cursor of the next page (0 at the end). Entries `at` cannot read
+/// are skipped.
+pub fn page(
+    env: &Env,
+    len: u32,
+    cursor: u32,
+    limit: u32,
+    at: impl Fn(u32) -> Option<PurchaseRecord>,
+) -> (Vec<PurchaseRecord>, u32) {
+    let mut page = Vec::new(env);
+    if cursor >= len || limit == 0 {
+        return (page, 0);
+    }
+    let end = core::cmp::min(cursor + limit, len);
+    for i in cursor..end {
+        if let Some(record) = at(i) {
+            page.push_back(record);
+        }
+    }
+    let next_cursor = if end < len { end } else { 0 };
+    (page, next_cursor)
+}
@@ -1,29 +1,163 @@
 #![no_std]
+mod attestation;
+mod bundle;
+mod cart;
+mod catalog;
+mod delegation;
+mod entitlement;
 mod events;
+mod history;
+mod moderation;
+mod preorder;
+mod pricing;
+mod registry;
+mod resale;
+mod sale;
+mod supply;
+mod tips;
+mod voucher;
+
+pub use crate::attestation::{Attestation, AttestationError, AttributeClaim};
+pub use crate::bundle::Bundle;
+pub use crate::cart::CartItem;
+pub use crate::catalog::CatalogKey;
+pub use crate::entitlement::{Subscription, SubscriptionClient};
+pub use crate::history::{HistoryKey, PurchaseRecord};
+pub use crate::moderation::{ModerationRecord, ModerationStatus};
+pub use crate::preorder::{PreOrder, PreOrderError, PreOrderKey};
+pub use crate::pricing::{PriceBound, PriceRule, PriceScope, PriceViolation};
+pub use crate::registry::{CreatorRegistry, CreatorRegistryClient};
+pub use crate::sale::{Sale, SaleDiscount};
+pub use crate::voucher::Voucher;
 
 use crate::events::{
-    AdminTransferredEvent, ContentPriceSetEvent, InitializedEvent, MaxPriceClearedEvent,
-    MaxPriceSetEvent,
+    AccessOptionsSetEvent, AccessResoldEvent, AdminTransferredEvent, BundleCreatedEvent,
+    BundleUnlockedEvent, ContentGiftedEvent, ContentModeratedEvent, ContentPriceSetEvent,
+    ContentRegisteredEvent, ContentStatusEvent, InitializedEvent, MaxPriceClearedEvent,
+    MaxPriceSetEvent, PreOrdersSettledEvent, PurchaseRefundedEvent, ReleaseScheduledEvent,
+    SubscriberAccessEvent, VoucherRedeemedEvent,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, Env,
-    Symbol,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, Bytes,
+    BytesN, Env, Map, String, Symbol, Vec,
 };
 
+/// Maximum page size for `list_content`.
+const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Maximum number of access options a creator may offer per content item.
+const MAX_ACCESS_OPTIONS: u32 = 8;
+
 /// Metadata for a piece of content in a creator's catalog.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContentInfo {
-    /// Price in the contract's configured token (stroops / smallest unit).
+    /// Price of the first access option in the contract's configured token
+    /// (stroops / smallest unit); kept in sync with `set_content_price` and
+    /// `set_access_options`.
     pub price: i128,
     /// Whether the content is currently available for purchase.
     pub is_active: bool,
+    /// Creator-supplied hash of the off-chain content metadata.
+    pub metadata_hash: BytesN<32>,
 }
 
 #[cfg(test)]
 #[path = "tests/event_tests.rs"]
 mod event_tests;
 
+#[cfg(test)]
+#[path = "tests/catalog_tests.rs"]
+mod catalog_tests;
+
+#[cfg(test)]
+#[path = "tests/access_option_tests.rs"]
+mod access_option_tests;
+
+#[cfg(test)]
+#[path = "tests/bundle_tests.rs"]
+mod bundle_tests;
+
+#[cfg(test)]
+#[path = "tests/subscriber_access_tests.rs"]
+mod subscriber_access_tests;
+
+#[cfg(test)]
+#[path = "tests/fee_tests.rs"]
+mod fee_tests;
+
+#[cfg(test)]
+#[path = "tests/voucher_tests.rs"]
+mod voucher_tests;
+
+#[cfg(test)]
+#[path = "tests/refund_tests.rs"]
+mod refund_tests;
+
+#[cfg(test)]
+#[path = "tests/gift_tests.rs"]
+mod gift_tests;
+
+#[cfg(test)]
+#[path = "tests/moderation_tests.rs"]
+mod moderation_tests;
+
+#[cfg(test)]
+#[path = "tests/cart_tests.rs"]
+mod cart_tests;
+
+#[cfg(test)]
+#[path = "tests/preorder_tests.rs"]
+mod preorder_tests;
+
+#[cfg(test)]
+#[path = "tests/delegation_tests.rs"]
+mod delegation_tests;
+
+#[cfg(test)]
+#[path = "tests/supply_tests.rs"]
+mod supply_tests;
+
+#[cfg(test)]
+#[path = "tests/resale_tests.rs"]
+mod resale_tests;
+
+#[cfg(test)]
+#[path = "tests/pricing_tests.rs"]
+mod pricing_tests;
+
+#[cfg(test)]
+#[path = "tests/tip_tests.rs"]
+mod tip_tests;
+
+#[cfg(test)]
+#[path = "tests/sale_tests.rs"]
+mod sale_tests;
+
+#[cfg(test)]
+#[path = "tests/registry_tests.rs"]
+mod registry_tests;
+
+#[cfg(test)]
+#[path = "tests/history_tests.rs"]
+mod history_tests;
+
+#[cfg(test)]
+#[path = "tests/attestation_tests.rs"]
+mod attestation_tests;
+
+/// A creator-defined way to buy a piece of content (e.g. 48-hour rental,
+/// 30-day access, lifetime).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOption {
+    /// Ledgers of access granted from the purchase ledger, or `None` for
+    /// access that never expires.
+    pub duration_ledgers: Option<u64>,
+    /// Price in the contract's configured token.
+    pub price: i128,
+}
+
 /// A purchase record stored per (buyer, creator, content_id).
 /// `expiry` is the ledger sequence number after which the purchase is considered expired.
 /// A value of `u64::MAX` means the purchase never expires.
@@ -32,6 +166,22 @@ mod event_tests;
 pub struct Purchase {
     /// Ledger sequence at which this purchase expires (exclusive).
     pub expiry: u64,
+    /// Copy number of a permanent purchase of limited-edition content.
+    pub serial: Option<u32>,
+}
+
+/// What was paid for a direct purchase, kept for bundle credit and refunds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    /// Address that paid; differs from the access holder for gifts.
+    pub payer: Address,
+    /// Gross amount charged to the buyer.
+    pub amount: i128,
+    /// Platform fee taken out of `amount`.
+    pub fee: i128,
+    /// Ledger sequence of the purchase.
+    pub purchased_at: u64,
 }
 
 /// Storage keys for content access contract
@@ -50,6 +200,88 @@ pub enum DataKey {
     MaxPrice,
     /// Whether the contract is paused (emergency stop)
     Paused,
+    /// Catalog entry: (creator, content_id) -> ContentInfo
+    Content(Address, u64),
+    /// Registered content IDs in registration order, in persistent storage: see `CatalogKey`
+    Catalog(CatalogKey),
+    /// Creator-defined pricing schedule: (creator, content_id) -> Vec<AccessOption>
+    AccessOptions(Address, u64),
+    /// Payment for a direct purchase: (buyer, creator, content_id) -> Receipt
+    Receipt(Address, Address, u64),
+    /// Next bundle ID to assign
+    NextBundleId,
+    /// Bundle definition: bundle_id -> Bundle
+    Bundle(u64),
+    /// Bundles containing a content item: (creator, content_id) -> Vec<u64>
+    ContentBundles(Address, u64),
+    /// Bundle purchase record: (buyer, bundle_id) -> Purchase
+    BundleAccess(Address, u64),
+    /// Subscription contract queried for subscriber access: Address
+    SubscriptionContract,
+    /// Content included for subscribers: (creator, content_id) -> minimum plan tier
+    SubscriberAccess(Address, u64),
+    /// Platform fee on sales in basis points (0 when unset)
+    FeeBps,
+    /// Recipient of platform fees (admin when unset)
+    FeeRecipient,
+    /// Creator's ed25519 voucher signing key: creator -> BytesN<32>
+    VoucherKey(Address),
+    /// Redemptions of a voucher nonce: (creator, nonce) -> u32
+    VoucherUses(Address, u64),
+    /// Ledgers after purchase within which buyers may request a refund (disabled when unset)
+    RefundWindow,
+    /// Pending buyer refund request: (buyer, creator, content_id) -> ledger requested
+    RefundRequest(Address, Address, u64),
+    /// Admin moderation state: (creator, content_id) -> ModerationRecord (Active when unset)
+    Moderation(Address, u64),
+    /// Release ledger of pre-order content: (creator, content_id) -> u64
+    ReleaseLedger(Address, u64),
+    /// Escrowed pre-order payments (persistent), one entry per pre-order
+    PreOrders(PreOrderKey),
+    /// Max delegates per purchase: (creator, content_id) -> u32 (0 when unset)
+    ShareLimit(Address, u64),
+    /// Addresses an owner shares a purchase with: (owner, creator, content_id) -> Vec<Address>
+    Delegates(Address, Address, u64),
+    /// Owner sharing with a delegate: (delegate, creator, content_id) -> Address
+    DelegatedFrom(Address, Address, u64),
+    /// Ledger of the owner's last delegation change: (owner, creator, content_id) -> u64
+    DelegationChangedAt(Address, Address, u64),
+    /// Limited-edition cap on permanent copies: (creator, content_id) -> u32
+    SupplyCap(Address, u64),
+    /// Permanent copies issued so far, capped or not: (creator, content_id) -> u32
+    SerialsIssued(Address, u64),
+    /// Creator royalty bps on resales; present only when resale is enabled: (creator, content_id) -> u32
+    ResaleRoyalty(Address, u64),
+    /// Resale asking price: (seller, creator, content_id) -> i128
+    Listing(Address, Address, u64),
+    /// Global price floor
+    MinPrice,
+    /// Admin price rule per category or trust level: PriceScope -> PriceRule
+    PriceRule(PriceScope),
+    /// Creator-assigned category code: (creator, content_id) -> u32
+    ContentCategory(Address, u64),
+    /// Admin-assigned creator trust level (0 when unset): creator -> u32
+    TrustLevel(Address),
+    /// Present when buyers may pay above the price: (creator, content_id) -> ()
+    PayWhatYouWant(Address, u64),
+    /// Platform fee on tips in basis points
+    TipFeeBps,
+    /// Gross tips received by a creator: creator -> i128
+    TipTotal(Address),
+    /// Scheduled sale on one item: (creator, content_id) -> Sale
+    Sale(Address, u64),
+    /// Scheduled sale on all of a creator's content: creator -> Sale
+    CreatorSale(Address),
+    /// Creator-registry contract gating pricing and sales: Address
+    CreatorRegistry,
+    /// Purchase history indexes, in persistent storage: see `HistoryKey`
+    History(HistoryKey),
+    /// Approved attestor key: public key -> ledger sequence of approval
+    Attestor(BytesN<32>),
+    /// Attribute claim: (address, attribute) -> AttributeClaim
+    Claim(Address, u32),
+    /// Attribute required to buy or access: (creator, content_id) -> u32
+    RequiredAttribute(Address, u64),
 }
 
 /// Per-contract error codes for the **content-access** contract.
@@ -69,6 +301,54 @@ pub enum DataKey {
 /// | 9 | `InvalidMaxPrice` |
 /// | 10 | `Paused` |
 /// | 11 | `InvalidExpiry` |
+/// | 12 | `ContentInactive` |
+/// | 13 | `ContentAlreadyRegistered` |
+/// | 14 | `ContentNotFound` |
+/// | 15 | `AccessOptionNotFound` |
+/// | 16 | `TooManyAccessOptions` |
+/// | 17 | `BundleNotFound` |
+/// | 18 | `InvalidBundle` |
+/// | 19 | `InvalidFeeRecipient` |
+/// | 20 | `InvalidFeeBps` |
+/// | 21 | `VoucherKeyNotSet` |
+/// | 22 | `VoucherExpired` |
+/// | 23 | `VoucherExhausted` |
+/// | 24 | `VoucherNotForBuyer` |
+/// | 25 | `NotRefundable` |
+/// | 26 | `RefundWindowClosed` |
+/// | 27 | `NotRefunder` |
+/// | 28 | `AlreadyHasAccess` |
+/// | 29 | `ContentModerated` |
+/// | 30 | `InvalidCart` |
+/// | 31 | `NotYetReleased` |
+/// | 32 | `AlreadyReleased` |
+/// | 33 | `InvalidReleaseLedger` |
+/// | 34 | `DelegationLimitReached` |
+/// | 35 | `DelegationCooldown` |
+/// | 36 | `AlreadyDelegated` |
+/// | 37 | `SoldOut` |
+/// | 38 | `InvalidSupplyCap` |
+/// | 39 | `ResaleDisabled` |
+/// | 40 | `NotResellable` |
+/// | 41 | `ListingNotFound` |
+/// | 42 | `PriceBelowMin` |
+/// | 43 | `PriceExceedsCategoryMax` |
+/// | 44 | `PriceBelowCategoryMin` |
+/// | 45 | `PriceExceedsTrustMax` |
+/// | 46 | `PriceBelowTrustMin` |
+/// | 47 | `InvalidPriceRule` |
+/// | 48 | `AmountBelowPrice` |
+/// | 49 | `TipsDisabled` |
+/// | 50 | `InvalidSale` |
+/// | 51 | `CreatorNotRegistered` |
+/// | 52 | `AttestationError::AttestorNotApproved` |
+/// | 53 | `AttestationError::AttestationExpired` |
+/// | 54 | `AttestationError::AttestationRequired` |
+/// | 55 | `PreOrderError::EscrowPending` |
+/// | 56 | `PreOrderError::RefundsPending` |
+///
+/// A contract error enum holds at most 50 variants, so codes from 52 on live
+/// in feature-specific enums that continue this numbering.
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Error {
@@ -90,182 +370,2463 @@ pub enum Error {
     InvalidMaxPrice = 9,
     /// Code 10 – contract is paused; action not allowed.
     Paused = 10,
-    /// Code 11 – expiry_ledger must be greater than the current ledger sequence.
+    /// Code 11 – access option has a zero-ledger rental duration.
     InvalidExpiry = 11,
+    /// Code 12 – catalog entry is deactivated and cannot be purchased.
+    ContentInactive = 12,
+    /// Code 13 – (creator, content_id) is already in the catalog.
+    ContentAlreadyRegistered = 13,
+    /// Code 14 – (creator, content_id) is not in the catalog.
+    ContentNotFound = 14,
+    /// Code 15 – requested option index is not offered for this content.
+    AccessOptionNotFound = 15,
+    /// Code 16 – more than `MAX_ACCESS_OPTIONS` options supplied.
+    TooManyAccessOptions = 16,
+    /// Code 17 – no bundle with the given ID.
+    BundleNotFound = 17,
+    /// Code 18 – bundle members are empty, duplicated, limited editions or
+    /// exceed `MAX_BUNDLE_SIZE`.
+    InvalidBundle = 18,
+    /// Code 19 – fee recipient is the Stellar null account.
+    InvalidFeeRecipient = 19,
+    /// Code 20 – fee basis points exceed 10_000.
+    InvalidFeeBps = 20,
+    /// Code 21 – creator has not registered a voucher signing key.
+    VoucherKeyNotSet = 21,
+    /// Code 22 – voucher redeemed at or after its `expires_at` ledger.
+    VoucherExpired = 22,
+    /// Code 23 – voucher nonce has reached its `max_uses`.
+    VoucherExhausted = 23,
+    /// Code 24 – voucher is bound to a different buyer.
+    VoucherNotForBuyer = 24,
+    /// Code 25 – no refundable direct purchase (never bought, bundle-only,
+    /// credited towards a bundle, or already refunded).
+    NotRefundable = 25,
+    /// Code 26 – buyer refund requests are disabled or the purchase is past the window.
+    RefundWindowClosed = 26,
+    /// Code 27 – refunds may only be issued by the content creator or the admin.
+    NotRefunder = 27,
+    /// Code 28 – gift recipient already holds valid access to the content.
+    AlreadyHasAccess = 28,
+    /// Code 29 – content is suspended or removed by moderation.
+    ContentModerated = 29,
+    /// Code 30 – cart empty, over the size cap, or listing an item twice.
+    InvalidCart = 30,
+    /// Code 31 – content is a pre-order whose release ledger has not been reached.
+    NotYetReleased = 31,
+    /// Code 32 – content has already been released or sold; its schedule can
+    /// no longer change.
+    AlreadyReleased = 32,
+    /// Code 33 – release ledger must be after the current ledger.
+    InvalidReleaseLedger = 33,
+    /// Code 34 – owner already shares with the creator's limit of delegates, or the
+    /// requested limit exceeds `MAX_SHARE_LIMIT`.
+    DelegationLimitReached = 34,
+    /// Code 35 – delegation changed again before the cooldown elapsed.
+    DelegationCooldown = 35,
+    /// Code 36 – delegate is the owner or already shares another owner's purchase.
+    AlreadyDelegated = 36,
+    /// Code 37 – every copy of a limited edition has been issued.
+    SoldOut = 37,
+    /// Code 38 – supply cap is zero, below the copies already issued, or raised after
+    /// sales began.
+    InvalidSupplyCap = 38,
+    /// Code 39 – creator has not enabled resale for the content.
+    ResaleDisabled = 39,
+    /// Code 40 – seller holds no permanent direct purchase of the content.
+    NotResellable = 40,
+    /// Code 41 – seller has no active listing for the content.
+    ListingNotFound = 41,
+    /// Code 42 – price is below the global floor.
+    PriceBelowMin = 42,
+    /// Code 43 – price exceeds the cap for the content's category.
+    PriceExceedsCategoryMax = 43,
+    /// Code 44 – price is below the floor for the content's category.
+    PriceBelowCategoryMin = 44,
+    /// Code 45 – price exceeds the cap for the creator's trust level.
+    PriceExceedsTrustMax = 45,
+    /// Code 46 – price is below the floor for the creator's trust level.
+    PriceBelowTrustMin = 46,
+    /// Code 47 – price rule bound is not positive, or its floor is above its cap.
+    InvalidPriceRule = 47,
+    /// Code 48 – buyer-chosen amount is below the selected option's price.
+    AmountBelowPrice = 48,
+    /// Code 49 – amount exceeds the price but the content is not pay-what-you-want.
+    TipsDisabled = 49,
+    /// Code 50 – sale discount is not positive, exceeds 10_000 bps, or its window is
+    /// empty or already over.
+    InvalidSale = 50,
+    /// Code 51 – creator registry is enabled and does not list the creator.
+    CreatorNotRegistered = 51,
+}
+
+/// Check a creator's price against the admin price rules; bundles pass
+/// `None` for `content_id`.
+fn require_valid_price(env: &Env, creator: &Address, content_id: Option<u64>, price: i128) {
+    if price <= 0 {
+        panic_with_error!(env, Error::InvalidPrice);
+    }
+    registry::require_registered(env, creator);
+    pricing::require_within_rules(env, creator, content_id, price);
+}
+
+/// First price rule broken by any option currently offered for (creator, content_id).
+fn price_violation(env: &Env, creator: &Address, content_id: u64) -> Option<PriceViolation> {
+    access_options(env, creator, content_id)
+        .iter()
+        .find_map(|option| pricing::violation(env, creator, Some(content_id), option.price))
+}
+
+/// Options offered for (creator, content_id).
+///
+/// Content without a creator-defined schedule falls back to a single lifetime
+/// option at the legacy `ContentPrice`, or to no options if unpriced.
+fn access_options(env: &Env, creator: &Address, content_id: u64) -> Vec<AccessOption> {
+    if let Some(options) = env
+        .storage()
+        .instance()
+        .get(&DataKey::AccessOptions(creator.clone(), content_id))
+    {
+        return options;
+    }
+    let mut options = Vec::new(env);
+    if let Some(price) = env
+        .storage()
+        .instance()
+        .get::<DataKey, i128>(&DataKey::ContentPrice(creator.clone(), content_id))
+    {
+        options.push_back(AccessOption {
+            duration_ledgers: None,
+            price,
+        });
+    }
+    options
+}
+
+/// Latest expiry among the buyer's own direct and bundle purchases of the
+/// content, or `None` if the buyer never bought it.
+fn own_expiry(env: &Env, buyer: &Address, creator: &Address, content_id: u64) -> Option<u64> {
+    let direct = env
+        .storage()
+        .instance()
+        .get::<DataKey, Purchase>(&DataKey::Access(buyer.clone(), creator.clone(), content_id))
+        .map(|p| p.expiry);
+    let via_bundle = bundle::access_expiry(env, buyer, creator, content_id);
+    core::cmp::max(direct, via_bundle)
+}
+
+/// Drop `owner`'s delegations once they no longer hold a valid purchase of
+/// the content themselves, so the delegates are free to be delegated again.
+fn release_delegations(env: &Env, owner: &Address, creator: &Address, content_id: u64) {
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    let still_owned = own_expiry(env, owner, creator, content_id).is_some_and(|e| e > current_seq);
+    if !still_owned {
+        delegation::clear(env, owner, creator, content_id);
+    }
+}
+
+/// Like `own_expiry`, but also counting a purchase shared with `buyer` by
+/// its owner; delegated access expires with the owner's purchase.
+fn access_expiry(env: &Env, buyer: &Address, creator: &Address, content_id: u64) -> Option<u64> {
+    let delegated = delegation::owner_of(env, buyer, creator, content_id)
+        .and_then(|owner| own_expiry(env, &owner, creator, content_id));
+    core::cmp::max(own_expiry(env, buyer, creator, content_id), delegated)
+}
+
+fn null_account_address(env: &Env) -> Address {
+    Address::from_string(&String::from_str(
+        env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ))
+}
+
+fn require_valid_fee_recipient(env: &Env, addr: &Address) {
+    if addr == &null_account_address(env) {
+        panic_with_error!(env, Error::InvalidFeeRecipient);
+    }
+}
+
+/// Platform fee in basis points must not exceed 100% (10_000 bps).
+fn require_valid_fee_bps(env: &Env, fee_bps: u32) {
+    if fee_bps > 10_000 {
+        panic_with_error!(env, Error::InvalidFeeBps);
+    }
+}
+
+fn fee_recipient(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeRecipient)
+        .or_else(|| env.storage().instance().get(&DataKey::Admin))
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized))
+}
+
+/// Platform fee owed on a sale of `gross`.
+fn platform_fee(env: &Env, gross: i128) -> i128 {
+    let fee_bps: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
+    (gross * fee_bps as i128) / 10000
+}
+
+/// Move `gross` from `payer`, splitting the platform fee off to the fee
+/// recipient and the remainder to `creator`. Returns the fee taken.
+fn settle(env: &Env, payer: &Address, creator: &Address, gross: i128) -> i128 {
+    let fee = platform_fee(env, gross);
+    let token_client = token_client(env);
+    if fee > 0 {
+        token_client.transfer(payer, &fee_recipient(env), &fee);
+    }
+    if gross - fee > 0 {
+        token_client.transfer(payer, creator, &(gross - fee));
+    }
+    fee
+}
+
+/// Store a direct purchase and its receipt. Permanent purchases of
+/// limited-edition content take the next serial.
+///
+/// # Errors
+/// - `SoldOut` – the content's supply cap has been reached.
+fn record_purchase(
+    env: &Env,
+    holder: &Address,
+    creator: &Address,
+    content_id: u64,
+    expiry: u64,
+    receipt: &Receipt,
+) {
+    // Only permanent copies count against a supply cap; rentals are unlimited.
+    let serial = if expiry == u64::MAX {
+        supply::take_serial(env, creator, content_id)
+    } else {
+        None
+    };
+    env.storage().instance().set(
+        &DataKey::Access(holder.clone(), creator.clone(), content_id),
+        &Purchase { expiry, serial },
+    );
+    env.storage().instance().set(
+        &DataKey::Receipt(holder.clone(), creator.clone(), content_id),
+        receipt,
+    );
+    history::add(env, holder, creator, content_id, receipt.amount);
+}
+
+/// Check that (creator, content_id) can be bought with `option` and return
+/// its list price, the price charged now (after any running sale) and the
+/// expiry a purchase made now would get.
+fn price_option(env: &Env, creator: &Address, content_id: u64, option: u32) -> (i128, i128, u64) {
+    if env
+        .storage()
+        .instance()
+        .get::<DataKey, ContentInfo>(&DataKey::Content(creator.clone(), content_id))
+        .is_some_and(|info| !info.is_active)
+    {
+        panic_with_error!(env, Error::ContentInactive);
+    }
+
+    moderation::require_purchasable(env, creator, content_id);
+    registry::require_registered(env, creator);
+
+    let options = access_options(env, creator, content_id);
+    if options.is_empty() {
+        panic_with_error!(env, Error::ContentPriceNotSet);
+    }
+    let selected = options
+        .get(option)
+        .unwrap_or_else(|| panic_with_error!(env, Error::AccessOptionNotFound));
+    let expiry = match selected.duration_ledgers {
+        Some(duration) => access_start(env, creator, content_id).saturating_add(duration),
+        None => u64::MAX,
+    };
+    let price = sale::price(env, creator, content_id, option, selected.price);
+    (selected.price, price, expiry)
+}
+
+/// Ledger from which a purchase made now can be used: the current ledger,
+/// or the release ledger for pre-orders. Rental durations count from here.
+fn access_start(env: &Env, creator: &Address, content_id: u64) -> u64 {
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    preorder::release_ledger(env, creator, content_id)
+        .map_or(current_seq, |release| core::cmp::max(release, current_seq))
+}
+
+/// Transfers a payer owes for one or more purchases, accumulated so that a
+/// checkout pays each recipient once.
+struct Payments {
+    /// Pre-order payments to hold in the contract.
+    escrowed: i128,
+    /// Platform fees owed to the fee recipient.
+    fee: i128,
+    /// Net proceeds owed to each creator.
+    owed: Map<Address, i128>,
+}
+
+impl Payments {
+    fn new(env: &Env) -> Self {
+        Payments {
+            escrowed: 0,
+            fee: 0,
+            owed: Map::new(env),
+        }
+    }
+
+    /// Make the accumulated transfers from `payer`.
+    fn pay(self, env: &Env, payer: &Address) {
+        let token_client = token_client(env);
+        if self.escrowed > 0 {
+            token_client.transfer(payer, &env.current_contract_address(), &self.escrowed);
+        }
+        if self.fee > 0 {
+            token_client.transfer(payer, &fee_recipient(env), &self.fee);
+        }
+        for (creator, amount) in self.owed.iter() {
+            if amount > 0 {
+                token_client.transfer(payer, &creator, &amount);
+            }
+        }
+    }
+}
+
+/// Charge `gross` to `payments` for `holder`'s purchase of (creator, content_id).
+///
+/// Pre-order payments are held by the contract until `settle_preorders`;
+/// everything else is split between the fee recipient and the creator.
+/// Returns the platform fee. Fails if `holder` still has an unsettled
+/// pre-order of the item.
+fn collect(
+    env: &Env,
+    payments: &mut Payments,
+    payer: &Address,
+    holder: &Address,
+    creator: &Address,
+    content_id: u64,
+    gross: i128,
+) -> i128 {
+    preorder::require_not_pending(env, creator, content_id, holder);
+    let fee = platform_fee(env, gross);
+    if preorder::is_unreleased(env, creator, content_id) {
+        payments.escrowed += gross;
+        preorder::add(
+            env,
+            creator,
+            content_id,
+            PreOrder {
+                holder: holder.clone(),
+                payer: payer.clone(),
+                gross,
+                fee,
+            },
+        );
+    } else {
+        payments.fee += fee;
+        let owed = payments.owed.get(creator.clone()).unwrap_or(0);
+        payments.owed.set(creator.clone(), owed + gross - fee);
+    }
+    fee
+}
+
+/// Charge `payer` for `option` on (creator, content_id) and grant `holder`
+/// access. Returns the list price, the gross price charged and the platform fee.
+fn sell(
+    env: &Env,
+    payer: &Address,
+    holder: &Address,
+    creator: &Address,
+    content_id: u64,
+    option: u32,
+) -> (i128, i128, i128) {
+    attestation::require_allowed(env, holder, creator, content_id);
+    let (list_price, price, expiry) = price_option(env, creator, content_id, option);
+
+    // Split payment between the platform and the creator (or escrow a pre-order)
+    let mut payments = Payments::new(env);
+    let fee = collect(
+        env,
+        &mut payments,
+        payer,
+        holder,
+        creator,
+        content_id,
+        price,
+    );
+    payments.pay(env, payer);
+    record_purchase(
+        env,
+        holder,
+        creator,
+        content_id,
+        expiry,
+        &Receipt {
+            payer: payer.clone(),
+            amount: price,
+            fee,
+            purchased_at: env.ledger().sequence() as u64,
+        },
+    );
+    (list_price, price, fee)
+}
+
+/// Whether `holder` currently has access through a purchase, bundle or
+/// subscription. Removed and unreleased content grants no access.
+fn holds_access(env: &Env, holder: &Address, creator: &Address, content_id: u64) -> bool {
+    if moderation::is_removed(env, creator, content_id)
+        || preorder::is_unreleased(env, creator, content_id)
+        || !attestation::is_allowed(env, holder, creator, content_id)
+    {
+        return false;
+    }
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    access_expiry(env, holder, creator, content_id).is_some_and(|e| e > current_seq)
+        || entitlement::is_entitled(env, holder, creator, content_id)
+}
+
+/// Refunds are issued by the content creator or the admin.
+fn require_refunder(env: &Env, refunder: &Address, creator: &Address) {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized));
+    if refunder != creator && refunder != &admin {
+        panic_with_error!(env, Error::NotRefunder);
+    }
+}
+
+fn token_client(env: &Env) -> token::Client<'_> {
+    let token_address: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenAddress)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized));
+    token::Client::new(env, &token_address)
+}
+
+/// Mirror the current price of (creator, content_id)'s first access option
+/// into its catalog entry, if registered.
+fn sync_catalog_price(env: &Env, creator: &Address, content_id: u64) {
+    let key = DataKey::Content(creator.clone(), content_id);
+    let Some(mut info) = env.storage().instance().get::<DataKey, ContentInfo>(&key) else {
+        return;
+    };
+    if let Some(option) = access_options(env, creator, content_id).get(0) {
+        if info.price != option.price {
+            info.price = option.price;
+            env.storage().instance().set(&key, &info);
+        }
+    }
 }
 
-#[contract]
-pub struct ContentAccess;
+fn set_content_status(env: &Env, creator: Address, content_id: u64, is_active: bool) {
+    creator.require_auth();
+    let key = DataKey::Content(creator.clone(), content_id);
+    let mut info: ContentInfo = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::ContentNotFound));
+    info.is_active = is_active;
+    env.storage().instance().set(&key, &info);
+
+    let topic = if is_active {
+        "content_activated"
+    } else {
+        "content_deactivated"
+    };
+    env.events().publish(
+        (Symbol::new(env, topic), creator.clone()),
+        ContentStatusEvent {
+            creator,
+            content_id,
+        },
+    );
+}
+
+#[contract]
+pub struct ContentAccess;
+
+#[contractimpl]
+impl ContentAccess {
+    /// Initialize the contract with admin and token address
+    pub fn initialize(env: Env, admin: Address, token_address: Address) {
+        admin.require_auth();
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        let token_client = token::Client::new(&env, &token_address);
+        let _ = token_client.balance(&admin);
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenAddress, &token_address);
+
+        env.events().publish(
+            (Symbol::new(&env, "initialized"),),
+            InitializedEvent {
+                admin: admin.clone(),
+            },
+        );
+    }
+
+    /// Unlock content for a buyer by transferring payment to creator.
+    ///
+    /// `option` indexes into `get_access_options(creator, content_id)`; the
+    /// selected option's price, discounted by any running sale, is charged
+    /// and its duration determines the purchase expiry. Index `0` of
+    /// unscheduled content is lifetime access at the content price.
+    ///
+    /// # Errors
+    /// - `ContentPriceNotSet` – no price or schedule for (creator, content_id).
+    /// - `AccessOptionNotFound` – `option` is out of range.
+    /// - `ContentInactive` – the catalog entry has been deactivated. Buyers
+    ///   with a still-valid purchase are unaffected.
+    /// - `SoldOut` – a permanent option was chosen and every copy of the
+    ///   limited edition has been issued.
+    /// - `CreatorNotRegistered` – the creator registry is enabled and does
+    ///   not list `creator`.
+    /// - `AttestationRequired` – the content requires an attribute the buyer
+    ///   holds no valid claim for.
+    /// - `EscrowPending` – the buyer's earlier pre-order of the item has not
+    ///   been settled yet (see `settle_preorders`).
+    ///
+    /// # Panics (auth)
+    /// - Buyer must authorize the transaction.
+    pub fn unlock_content(
+        env: Env,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+        option: u32,
+    ) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+
+        // Cache current ledger sequence once (hot path optimization).
+        let current_seq: u64 = env.ledger().sequence() as u64;
+
+        // Check if already unlocked (idempotent) – but re-check expiry.
+        let access_key = DataKey::Access(buyer.clone(), creator.clone(), content_id);
+        if let Some(existing) = env
+            .storage()
+            .instance()
+            .get::<DataKey, Purchase>(&access_key)
+        {
+            // If the existing purchase is still valid, treat as no-op.
+            if existing.expiry > current_seq {
+                return;
+            }
+            // Expired purchase: allow re-purchase by falling through.
+        }
+
+        let (list_price, price, fee) = sell(&env, &buyer, &buyer, &creator, content_id, option);
+
+        // Emit event (construct symbol once)
+        // topics: (content_unlocked, buyer, creator)  data: (content_id, list_price, gross, fee, net)
+        let topic = Symbol::new(&env, "content_unlocked");
+        env.events().publish(
+            (topic, buyer.clone(), creator.clone()),
+            (content_id, list_price, price, fee, price - fee),
+        );
+    }
+
+    /// Unlock content paying a buyer-chosen `amount` at or above the price of
+    /// `option`. Buyer must authorize.
+    ///
+    /// The price is charged as in `unlock_content`; on pay-what-you-want
+    /// content the excess is a tip, paid to the creator minus the tip fee and
+    /// announced with a `content_tipped` event. Unlike `unlock_content` this
+    /// is not idempotent.
+    ///
+    /// # Errors
+    /// - `AlreadyHasAccess` – buyer already holds a valid purchase.
+    /// - `AmountBelowPrice` – `amount` is below the option's price.
+    /// - `TipsDisabled` – `amount` exceeds the price of fixed-price content.
+    /// - Otherwise as `unlock_content`.
+    pub fn unlock_content_with_amount(
+        env: Env,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+        option: u32,
+        amount: i128,
+    ) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        if env
+            .storage()
+            .instance()
+            .get::<DataKey, Purchase>(&DataKey::Access(buyer.clone(), creator.clone(), content_id))
+            .is_some_and(|p| p.expiry > current_seq)
+        {
+            panic_with_error!(&env, Error::AlreadyHasAccess);
+        }
+
+        let (list_price, price, fee) = sell(&env, &buyer, &buyer, &creator, content_id, option);
+        if amount < price {
+            panic_with_error!(&env, Error::AmountBelowPrice);
+        }
+        let tip = amount - price;
+        if tip > 0 {
+            if !tips::is_enabled(&env, &creator, content_id) {
+                panic_with_error!(&env, Error::TipsDisabled);
+            }
+            let tip_fee = tips::pay(&env, &buyer, &creator, tip);
+            env.events().publish(
+                (
+                    Symbol::new(&env, "content_tipped"),
+                    buyer.clone(),
+                    creator.clone(),
+                ),
+                (content_id, tip, tip_fee),
+            );
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "content_unlocked"), buyer, creator),
+            (content_id, list_price, price, fee, price - fee),
+        );
+    }
+
+    /// Let buyers of (creator, content_id) pay above the price, treating the
+    /// price as a floor. Creator must authorize.
+    pub fn set_pay_what_you_want(env: Env, creator: Address, content_id: u64, enabled: bool) {
+        creator.require_auth();
+        let key = DataKey::PayWhatYouWant(creator.clone(), content_id);
+        if enabled {
+            env.storage().instance().set(&key, &());
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        env.events().publish(
+            (Symbol::new(&env, "pay_what_you_want_set"), creator),
+            (content_id, enabled),
+        );
+    }
+
+    /// Whether (creator, content_id) accepts payments above its price.
+    pub fn is_pay_what_you_want(env: Env, creator: Address, content_id: u64) -> bool {
+        tips::is_enabled(&env, &creator, content_id)
+    }
+
+    /// Gross tips `creator` has received.
+    pub fn get_tip_total(env: Env, creator: Address) -> i128 {
+        tips::total(&env, &creator)
+    }
+
+    /// Buy several content items in one transaction.
+    ///
+    /// Each item is priced and recorded as `unlock_content` would, with one
+    /// `content_unlocked` event per item, but the buyer makes a single
+    /// transfer per creator plus one for the combined platform fee. Items the
+    /// buyer already holds a valid purchase of are skipped. Any failing item
+    /// aborts the whole call, so nothing is charged.
+    ///
+    /// # Errors
+    /// - `InvalidCart` – empty, more than `MAX_CART_SIZE` items, or duplicates.
+    /// - Otherwise as `unlock_content`, for any item.
+    pub fn unlock_many(env: Env, buyer: Address, items: Vec<CartItem>) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+        cart::require_valid_items(&env, &items);
+
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        let mut payments = Payments::new(&env);
+        for item in items.iter() {
+            let access_key = DataKey::Access(buyer.clone(), item.creator.clone(), item.content_id);
+            if env
+                .storage()
+                .instance()
+                .get::<DataKey, Purchase>(&access_key)
+                .is_some_and(|p| p.expiry > current_seq)
+            {
+                continue;
+            }
+
+            attestation::require_allowed(&env, &buyer, &item.creator, item.content_id);
+            let (list_price, price, expiry) =
+                price_option(&env, &item.creator, item.content_id, item.option);
+            let fee = collect(
+                &env,
+                &mut payments,
+                &buyer,
+                &buyer,
+                &item.creator,
+                item.content_id,
+                price,
+            );
+
+            record_purchase(
+                &env,
+                &buyer,
+                &item.creator,
+                item.content_id,
+                expiry,
+                &Receipt {
+                    payer: buyer.clone(),
+                    amount: price,
+                    fee,
+                    purchased_at: current_seq,
+                },
+            );
+            env.events().publish(
+                (
+                    Symbol::new(&env, "content_unlocked"),
+                    buyer.clone(),
+                    item.creator.clone(),
+                ),
+                (item.content_id, list_price, price, fee, price - fee),
+            );
+        }
+
+        payments.pay(&env, &buyer);
+    }
+
+    /// Buy access to (creator, content_id) for `recipient`, paid by `payer`.
+    ///
+    /// Charges like `unlock_content`, but the `Access` record goes to the
+    /// recipient and a later refund is returned to `payer`. Unlike
+    /// `unlock_content` this is not idempotent.
+    ///
+    /// # Errors
+    /// - `AlreadyHasAccess` – recipient already has valid access (purchase,
+    ///   bundle or subscription).
+    /// - Otherwise as `unlock_content`.
+    ///
+    /// # Panics (auth)
+    /// - Payer must authorize the transaction.
+    pub fn gift_content(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        creator: Address,
+        content_id: u64,
+        option: u32,
+    ) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        payer.require_auth();
+        if holds_access(&env, &recipient, &creator, content_id) {
+            panic_with_error!(&env, Error::AlreadyHasAccess);
+        }
+
+        let (_, price, fee) = sell(&env, &payer, &recipient, &creator, content_id, option);
+
+        env.events().publish(
+            (Symbol::new(&env, "content_gifted"), payer, recipient),
+            ContentGiftedEvent {
+                creator,
+                content_id,
+                price,
+                fee,
+            },
+        );
+    }
+
+    /// Check if buyer has valid (non-expired) access to content, either
+    /// directly, through a bundle containing it, or through a qualifying
+    /// subscription to the creator when the content is included for subscribers.
+    pub fn has_access(env: Env, buyer: Address, creator: Address, content_id: u64) -> bool {
+        holds_access(&env, &buyer, &creator, content_id)
+    }
+
+    /// Verify that `claimer` may access (creator, content_id): either a
+    /// non-expired purchase or a qualifying subscription (see `has_access`).
+    ///
+    /// # Panics (contract errors)
+    /// - `ContentModerated` – content has been removed by moderation.
+    /// - `NotYetReleased`  – pre-order content before its release ledger.
+    /// - `AttestationRequired` – `claimer` lacks a valid claim for the
+    ///   content's required attribute.
+    /// - `NotBuyer`        – no purchase record exists for `claimer`.
+    /// - `PurchaseExpired` – purchase exists but has expired.
+    pub fn verify_access(env: Env, claimer: Address, creator: Address, content_id: u64) {
+        if moderation::is_removed(&env, &creator, content_id) {
+            panic_with_error!(&env, Error::ContentModerated);
+        }
+        if preorder::is_unreleased(&env, &creator, content_id) {
+            panic_with_error!(&env, Error::NotYetReleased);
+        }
+        attestation::require_allowed(&env, &claimer, &creator, content_id);
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        let expiry = access_expiry(&env, &claimer, &creator, content_id);
+        if expiry.is_some_and(|e| e > current_seq)
+            || entitlement::is_entitled(&env, &claimer, &creator, content_id)
+        {
+            return;
+        }
+
+        match expiry {
+            None => panic_with_error!(&env, Error::NotBuyer),
+            Some(_) => panic_with_error!(&env, Error::PurchaseExpired),
+        }
+    }
+
+    /// Include a creator's content for their subscribers. Creator must authorize.
+    ///
+    /// With `min_tier` set, only subscribers whose plan tier is at least
+    /// `min_tier` gain access; `None` admits any active subscriber.
+    pub fn include_for_subscribers(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        min_tier: Option<u32>,
+    ) {
+        creator.require_auth();
+        env.storage().instance().set(
+            &DataKey::SubscriberAccess(creator.clone(), content_id),
+            &min_tier.unwrap_or(0),
+        );
+        env.events().publish(
+            (Symbol::new(&env, "subscriber_access_set"), creator.clone()),
+            SubscriberAccessEvent {
+                creator,
+                content_id,
+                min_tier,
+            },
+        );
+    }
+
+    /// Stop including a creator's content for subscribers. Creator must authorize.
+    pub fn exclude_from_subscribers(env: Env, creator: Address, content_id: u64) {
+        creator.require_auth();
+        env.storage()
+            .instance()
+            .remove(&DataKey::SubscriberAccess(creator.clone(), content_id));
+        env.events().publish(
+            (
+                Symbol::new(&env, "subscriber_access_removed"),
+                creator.clone(),
+            ),
+            SubscriberAccessEvent {
+                creator,
+                content_id,
+                min_tier: None,
+            },
+        );
+    }
+
+    /// Minimum plan tier required for subscriber access to (creator, content_id),
+    /// or `None` if the content is not included for subscribers.
+    pub fn subscriber_access(env: Env, creator: Address, content_id: u64) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::SubscriberAccess(creator, content_id))
+    }
+
+    /// Set the subscription contract queried for subscriber access. Only admin may call this.
+    pub fn set_subscription_contract(env: Env, subscription_contract: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::SubscriptionContract, &subscription_contract);
+        env.events().publish(
+            (Symbol::new(&env, "subscription_contract_set"), admin),
+            subscription_contract,
+        );
+    }
+
+    /// Get the configured subscription contract, if any.
+    pub fn subscription_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::SubscriptionContract)
+    }
+
+    /// Require creators to be registered in `registry` before they can price
+    /// content or have it sold; `None` turns the check off. Off by default.
+    /// Only admin may call this.
+    pub fn set_creator_registry(env: Env, registry: Option<Address>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::CreatorRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::CreatorRegistry),
+        }
+        env.events()
+            .publish((Symbol::new(&env, "creator_registry_set"), admin), registry);
+    }
+
+    /// Get the creator registry gating pricing and sales, if enabled.
+    pub fn creator_registry(env: Env) -> Option<Address> {
+        registry::contract(&env)
+    }
+
+    /// Get the price for (creator, content_id), discounted by any running
+    /// sale. Returns None if not set.
+    pub fn get_content_price(env: Env, creator: Address, content_id: u64) -> Option<i128> {
+        let key = DataKey::ContentPrice(creator.clone(), content_id);
+        env.storage()
+            .instance()
+            .get(&key)
+            .map(|list_price| sale::price(&env, &creator, content_id, 0, list_price))
+    }
+
+    /// Get the undiscounted price set for (creator, content_id). Returns None if not set.
+    pub fn get_list_price(env: Env, creator: Address, content_id: u64) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContentPrice(creator, content_id))
+    }
+
+    /// Schedule a sale on (creator, content_id), or on all of the creator's
+    /// content when `content_id` is `None`, replacing any previous one.
+    /// `None` for `sale` cancels it. Creator must authorize.
+    ///
+    /// While running, the sale discounts every access option: by the same
+    /// basis points, or to the fixed price given for that option's index.
+    /// List prices are unchanged. An item's own sale takes precedence over a
+    /// creator-wide one, and sale prices never drop below the admin price floor.
+    ///
+    /// # Errors
+    /// - `InvalidSale` – discount is not positive or above 10_000 bps, fixed
+    ///   prices are empty or more than `MAX_ACCESS_OPTIONS`, or
+    ///   `start_ledger..end_ledger` is empty or already over.
+    pub fn set_sale(env: Env, creator: Address, content_id: Option<u64>, sale: Option<Sale>) {
+        creator.require_auth();
+        if let Some(sale) = &sale {
+            sale::require_valid(&env, sale);
+        }
+        sale::set(&env, &creator, content_id, &sale);
+        env.events().publish(
+            (Symbol::new(&env, "sale_scheduled"), creator),
+            (content_id, sale),
+        );
+    }
+
+    /// Sale scheduled on (creator, content_id), or creator-wide when
+    /// `content_id` is `None`, whether or not it is running yet.
+    pub fn get_sale(env: Env, creator: Address, content_id: Option<u64>) -> Option<Sale> {
+        sale::get(&env, &creator, content_id)
+    }
+
+    /// Sale discounting (creator, content_id) right now, if any.
+    pub fn get_active_sale(env: Env, creator: Address, content_id: u64) -> Option<Sale> {
+        sale::active(&env, &creator, content_id)
+    }
+
+    /// Set the price for a creator's content. Creator must authorize.
+    ///
+    /// Also updates the catalog entry's price when the content is registered
+    /// and has no pricing schedule.
+    ///
+    /// # Errors
+    /// - `InvalidPrice` – `price` is not positive.
+    /// - The price breaks the most specific admin rule for each bound (content
+    ///   category, then creator trust level, then global), reported as
+    ///   `PriceExceedsCategoryMax` / `PriceBelowCategoryMin`,
+    ///   `PriceExceedsTrustMax` / `PriceBelowTrustMin` or
+    ///   `PriceExceedsMax` / `PriceBelowMin`.
+    /// - `CreatorNotRegistered` – the creator registry is enabled and does
+    ///   not list `creator`.
+    pub fn set_content_price(env: Env, creator: Address, content_id: u64, price: i128) {
+        creator.require_auth();
+        require_valid_price(&env, &creator, Some(content_id), price);
+
+        let key = DataKey::ContentPrice(creator.clone(), content_id);
+        env.storage().instance().set(&key, &price);
+        sync_catalog_price(&env, &creator, content_id);
+        env.events().publish(
+            (Symbol::new(&env, "content_price_set"), creator.clone()),
+            ContentPriceSetEvent {
+                creator,
+                content_id,
+                price,
+            },
+        );
+    }
+
+    /// Replace the pricing schedule for a creator's content. Creator must authorize.
+    ///
+    /// Buyers pick an option by index in `unlock_content`. Each option's price
+    /// is validated like `set_content_price`. Passing an empty list removes the
+    /// schedule, falling back to lifetime access at the content price. The
+    /// catalog entry's price follows the first option.
+    ///
+    /// # Errors
+    /// - `TooManyAccessOptions` – more than `MAX_ACCESS_OPTIONS` options.
+    /// - `InvalidExpiry` – an option has a zero-ledger duration.
+    /// - `InvalidPrice` / price rule errors – see `set_content_price`.
+    pub fn set_access_options(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        options: Vec<AccessOption>,
+    ) {
+        creator.require_auth();
+        if options.len() > MAX_ACCESS_OPTIONS {
+            panic_with_error!(&env, Error::TooManyAccessOptions);
+        }
+        for option in options.iter() {
+            if option.duration_ledgers == Some(0) {
+                panic_with_error!(&env, Error::InvalidExpiry);
+            }
+            require_valid_price(&env, &creator, Some(content_id), option.price);
+        }
+
+        let key = DataKey::AccessOptions(creator.clone(), content_id);
+        if options.is_empty() {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &options);
+        }
+        sync_catalog_price(&env, &creator, content_id);
+        env.events().publish(
+            (Symbol::new(&env, "access_options_set"), creator.clone()),
+            AccessOptionsSetEvent {
+                creator,
+                content_id,
+                options,
+            },
+        );
+    }
+
+    /// Options a buyer can choose from in `unlock_content`, in index order.
+    pub fn get_access_options(env: Env, creator: Address, content_id: u64) -> Vec<AccessOption> {
+        access_options(&env, &creator, content_id)
+    }
+
+    /// Define a bundle of the creator's content sold at one price. Creator must authorize.
+    ///
+    /// Returns the new bundle ID.
+    ///
+    /// # Errors
+    /// - `InvalidBundle` – members are empty, duplicated, exceed
+    ///   `MAX_BUNDLE_SIZE` or include a limited edition.
+    /// - `InvalidExpiry` – `duration_ledgers` is `Some(0)`.
+    /// - `InvalidPrice` / price rule errors – see `set_content_price`; bundles
+    ///   are held to trust-level and global rules only.
+    pub fn create_bundle(
+        env: Env,
+        creator: Address,
+        content_ids: Vec<u64>,
+        price: i128,
+        duration_ledgers: Option<u64>,
+    ) -> u64 {
+        creator.require_auth();
+        bundle::require_valid_members(&env, &creator, &content_ids);
+        if duration_ledgers == Some(0) {
+            panic_with_error!(&env, Error::InvalidExpiry);
+        }
+        require_valid_price(&env, &creator, None, price);
+
+        let bundle_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextBundleId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextBundleId, &(bundle_id + 1));
+        for content_id in content_ids.iter() {
+            bundle::index_member(&env, &creator, content_id, bundle_id);
+        }
+        let bundle = Bundle {
+            creator: creator.clone(),
+            content_ids,
+            price,
+            duration_ledgers,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Bundle(bundle_id), &bundle);
+
+        env.events().publish(
+            (Symbol::new(&env, "bundle_created"), creator),
+            BundleCreatedEvent { bundle_id, bundle },
+        );
+        bundle_id
+    }
+
+    /// Add a content item to an existing bundle. Bundle creator must authorize.
+    ///
+    /// Current bundle owners gain access to the new member without a new purchase.
+    ///
+    /// # Errors
+    /// - `BundleNotFound` – unknown `bundle_id`.
+    /// - `InvalidBundle` – already a member, a limited edition, or the bundle is full.
+    pub fn add_to_bundle(env: Env, bundle_id: u64, content_id: u64) {
+        let mut bundle = bundle::get(&env, bundle_id);
+        bundle.creator.require_auth();
+        bundle.content_ids.push_back(content_id);
+        bundle::require_valid_members(&env, &bundle.creator, &bundle.content_ids);
+
+        bundle::index_member(&env, &bundle.creator, content_id, bundle_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::Bundle(bundle_id), &bundle);
+        env.events().publish(
+            (Symbol::new(&env, "bundle_updated"), bundle.creator.clone()),
+            BundleCreatedEvent { bundle_id, bundle },
+        );
+    }
+
+    /// Buy every member of a bundle in one transaction.
+    ///
+    /// The buyer is charged the bundle price minus what they paid for members
+    /// they still hold valid direct purchases of (see `get_bundle_price`).
+    /// Those purchases are traded in: their receipts are removed, so they can
+    /// no longer be refunded. Idempotent while the buyer's previous bundle
+    /// purchase is still valid.
+    ///
+    /// # Errors
+    /// - `Paused` – contract is paused.
+    /// - `BundleNotFound` – unknown `bundle_id`.
+    /// - `ContentInactive` – a member has been deactivated by the creator.
+    /// - `NotYetReleased` – a member is an unreleased pre-order; bundles are
+    ///   paid out immediately, so they cannot be escrowed.
+    pub fn unlock_bundle(env: Env, buyer: Address, bundle_id: u64) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+
+        let bundle = bundle::get(&env, bundle_id);
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        let bundle_key = DataKey::BundleAccess(buyer.clone(), bundle_id);
+        if env
+            .storage()
+            .instance()
+            .get::<DataKey, Purchase>(&bundle_key)
+            .is_some_and(|p| p.expiry > current_seq)
+        {
+            return;
+        }
+        for content_id in bundle.content_ids.iter() {
+            if env
+                .storage()
+                .instance()
+                .get::<DataKey, ContentInfo>(&DataKey::Content(bundle.creator.clone(), content_id))
+                .is_some_and(|info| !info.is_active)
+            {
+                panic_with_error!(&env, Error::ContentInactive);
+            }
+            moderation::require_purchasable(&env, &bundle.creator, content_id);
+            attestation::require_allowed(&env, &buyer, &bundle.creator, content_id);
+            if preorder::is_unreleased(&env, &bundle.creator, content_id) {
+                panic_with_error!(&env, Error::NotYetReleased);
+            }
+        }
+        registry::require_registered(&env, &bundle.creator);
+
+        let charged = bundle::quote(&env, &buyer, &bundle, current_seq);
+        bundle::redeem_credit(&env, &buyer, &bundle, current_seq);
+        let fee = settle(&env, &buyer, &bundle.creator, charged);
+
+        let expiry = match bundle.duration_ledgers {
+            Some(duration) => current_seq.saturating_add(duration),
+            None => u64::MAX,
+        };
+        env.storage().instance().set(
+            &bundle_key,
+            &Purchase {
+                expiry,
+                serial: None,
+            },
+        );
+        for content_id in bundle.content_ids.iter() {
+            let access_key = DataKey::Access(buyer.clone(), bundle.creator.clone(), content_id);
+            // Never shorten a longer-lived direct purchase.
+            let keep_existing = env
+                .storage()
+                .instance()
+                .get::<DataKey, Purchase>(&access_key)
+                .is_some_and(|p| p.expiry >= expiry);
+            if !keep_existing {
+                env.storage().instance().set(
+                    &access_key,
+                    &Purchase {
+                        expiry,
+                        serial: None,
+                    },
+                );
+                history::add(&env, &buyer, &bundle.creator, content_id, 0);
+            }
+        }
+
+        env.events().publish(
+            (
+                Symbol::new(&env, "bundle_unlocked"),
+                buyer,
+                bundle.creator.clone(),
+            ),
+            BundleUnlockedEvent {
+                bundle_id,
+                price: bundle.price,
+                credit: bundle.price - charged,
+                charged,
+                fee,
+            },
+        );
+    }
+
+    /// Get a bundle definition, if it exists.
+    pub fn get_bundle(env: Env, bundle_id: u64) -> Option<Bundle> {
+        env.storage().instance().get(&DataKey::Bundle(bundle_id))
+    }
+
+    /// Price `buyer` would pay for `bundle_id` right now, after credit for
+    /// members they already own.
+    pub fn get_bundle_price(env: Env, buyer: Address, bundle_id: u64) -> i128 {
+        let bundle = bundle::get(&env, bundle_id);
+        bundle::quote(&env, &buyer, &bundle, env.ledger().sequence() as u64)
+    }
+
+    /// Whether `buyer` holds a valid purchase of `bundle_id`.
+    pub fn has_bundle_access(env: Env, buyer: Address, bundle_id: u64) -> bool {
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        env.storage()
+            .instance()
+            .get::<DataKey, Purchase>(&DataKey::BundleAccess(buyer, bundle_id))
+            .is_some_and(|p| p.expiry > current_seq)
+    }
+
+    /// Register the ed25519 public key whose signatures `redeem_voucher`
+    /// accepts for `creator`. Creator must authorize.
+    ///
+    /// Rotating the key invalidates every outstanding voucher signed with the old one.
+    pub fn set_voucher_key(env: Env, creator: Address, public_key: BytesN<32>) {
+        creator.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::VoucherKey(creator.clone()), &public_key);
+        env.events()
+            .publish((Symbol::new(&env, "voucher_key_set"), creator), public_key);
+    }
+
+    /// Get the creator's voucher signing key, if registered.
+    pub fn voucher_key(env: Env, creator: Address) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::VoucherKey(creator))
+    }
+
+    /// Bytes the creator must sign for `voucher`.
+    pub fn voucher_message(env: Env, voucher: Voucher) -> Bytes {
+        voucher::message(&env, &voucher)
+    }
+
+    /// Number of times vouchers with (creator, nonce) have been redeemed.
+    pub fn voucher_uses(env: Env, creator: Address, nonce: u64) -> u32 {
+        voucher::uses(&env, &creator, nonce)
+    }
+
+    /// Approve an ed25519 attestor key whose signed `Attestation`s
+    /// `submit_attestation` accepts. Only admin may call this.
+    pub fn add_attestor(env: Env, public_key: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        let key = DataKey::Attestor(public_key.clone());
+        if !env.storage().instance().has(&key) {
+            env.storage()
+                .instance()
+                .set(&key, &(env.ledger().sequence() as u64));
+        }
+        env.events()
+            .publish((Symbol::new(&env, "attestor_added"),), public_key);
+    }
+
+    /// Revoke an attestor key. Every claim it signed stops counting, even if
+    /// the key is approved again later. Only admin may call this.
+    pub fn remove_attestor(env: Env, public_key: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .remove(&DataKey::Attestor(public_key.clone()));
+        env.events()
+            .publish((Symbol::new(&env, "attestor_removed"),), public_key);
+    }
+
+    /// Whether `public_key` is an approved attestor.
+    pub fn is_attestor(env: Env, public_key: BytesN<32>) -> bool {
+        attestation::approved_at(&env, &public_key).is_some()
+    }
+
+    /// Bytes an attestor must sign for `attestation`.
+    pub fn attestation_message(env: Env, attestation: Attestation) -> Bytes {
+        attestation::message(&env, &attestation)
+    }
+
+    /// Store an attestor-signed claim that `attestation.subject` holds
+    /// `attestation.attribute`, replacing any earlier claim for it. The
+    /// subject must authorize.
+    ///
+    /// # Errors
+    /// - `AttestorNotApproved` – `attestor` is not an approved key.
+    /// - `AttestationExpired` – current ledger is at or past `expires_at`.
+    ///
+    /// # Panics (auth)
+    /// - Subject must authorize; an invalid signature traps in the host.
+    pub fn submit_attestation(
+        env: Env,
+        attestation: Attestation,
+        attestor: BytesN<32>,
+        signature: BytesN<64>,
+    ) {
+        attestation.subject.require_auth();
+        attestation::submit(&env, &attestation, &attestor, &signature);
+        env.events().publish(
+            (
+                Symbol::new(&env, "attestation_submitted"),
+                attestation.subject,
+            ),
+            (attestation.attribute, attestation.expires_at),
+        );
+    }
+
+    /// Stored claim for (holder, attribute), whether or not it is still valid.
+    pub fn get_attestation(env: Env, holder: Address, attribute: u32) -> Option<AttributeClaim> {
+        attestation::claim(&env, &holder, attribute)
+    }
+
+    /// Whether `holder` has an unexpired claim for `attribute` from an
+    /// approved attestor.
+    pub fn has_attribute(env: Env, holder: Address, attribute: u32) -> bool {
+        attestation::holds(&env, &holder, attribute)
+    }
+
+    /// Require buyers and viewers of (creator, content_id) to hold a valid
+    /// claim for `attribute`; `None` lifts the requirement. Creator must
+    /// authorize.
+    pub fn set_required_attribute(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        attribute: Option<u32>,
+    ) {
+        creator.require_auth();
+        let key = DataKey::RequiredAttribute(creator.clone(), content_id);
+        match attribute {
+            Some(attribute) => env.storage().instance().set(&key, &attribute),
+            None => env.storage().instance().remove(&key),
+        }
+        env.events().publish(
+            (Symbol::new(&env, "required_attribute_set"), creator),
+            (content_id, attribute),
+        );
+    }
+
+    /// Attribute required to buy or access (creator, content_id), if any.
+    pub fn get_required_attribute(env: Env, creator: Address, content_id: u64) -> Option<u32> {
+        attestation::required(&env, &creator, content_id)
+    }
+
+    /// Redeem a creator-signed voucher for access to its content item.
+    ///
+    /// Charges the voucher price (split like `unlock_content`) and grants
+    /// access for the voucher's duration. A buyer who already holds valid
+    /// access is a no-op and does not consume a use.
+    ///
+    /// # Errors
+    /// - `Paused` – contract is paused.
+    /// - `VoucherKeyNotSet` – creator has no registered key.
+    /// - `VoucherExpired` – current ledger is at or past `expires_at`.
+    /// - `VoucherNotForBuyer` – voucher is bound to another buyer.
+    /// - `VoucherExhausted` – the nonce has been used `max_uses` times.
+    /// - `InvalidPrice` – negative voucher price.
+    /// - `PriceBelowMin` / `PriceBelowCategoryMin` / `PriceBelowTrustMin` –
+    ///   a paid voucher is priced below the admin price floor.
+    /// - `InvalidExpiry` – zero-ledger duration.
+    /// - `ContentInactive` – the catalog entry has been deactivated.
+    ///
+    /// # Panics (auth)
+    /// - Buyer must authorize; an invalid signature traps in the host.
+    pub fn redeem_voucher(env: Env, buyer: Address, voucher: Voucher, signature: BytesN<64>) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+        voucher::require_valid_signature(&env, &voucher, &signature);
+
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        if current_seq >= voucher.expires_at {
+            panic_with_error!(&env, Error::VoucherExpired);
+        }
+        if voucher.buyer.as_ref().is_some_and(|bound| bound != &buyer) {
+            panic_with_error!(&env, Error::VoucherNotForBuyer);
+        }
+        if voucher.price < 0 {
+            panic_with_error!(&env, Error::InvalidPrice);
+        }
+        // Free vouchers are grants, not sales, so only paid ones hit the floor.
+        if voucher.price > 0 {
+            pricing::require_above_floor(&env, &voucher.creator, voucher.content_id, voucher.price);
+        }
+        if voucher.duration_ledgers == Some(0) {
+            panic_with_error!(&env, Error::InvalidExpiry);
+        }
+
+        let creator = voucher.creator.clone();
+        let content_id = voucher.content_id;
+        let access_key = DataKey::Access(buyer.clone(), creator.clone(), content_id);
+        if env
+            .storage()
+            .instance()
+            .get::<DataKey, Purchase>(&access_key)
+            .is_some_and(|p| p.expiry > current_seq)
+        {
+            return;
+        }
+        if env
+            .storage()
+            .instance()
+            .get::<DataKey, ContentInfo>(&DataKey::Content(creator.clone(), content_id))
+            .is_some_and(|info| !info.is_active)
+        {
+            panic_with_error!(&env, Error::ContentInactive);
+        }
+        moderation::require_purchasable(&env, &creator, content_id);
+        registry::require_registered(&env, &creator);
+        attestation::require_allowed(&env, &buyer, &creator, content_id);
+
+        voucher::consume(&env, &voucher);
+        let mut payments = Payments::new(&env);
+        let fee = collect(
+            &env,
+            &mut payments,
+            &buyer,
+            &buyer,
+            &creator,
+            content_id,
+            voucher.price,
+        );
+        payments.pay(&env, &buyer);
+
+        let expiry = match voucher.duration_ledgers {
+            Some(duration) => access_start(&env, &creator, content_id).saturating_add(duration),
+            None => u64::MAX,
+        };
+        record_purchase(
+            &env,
+            &buyer,
+            &creator,
+            content_id,
+            expiry,
+            &Receipt {
+                payer: buyer.clone(),
+                amount: voucher.price,
+                fee,
+                purchased_at: current_seq,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "voucher_redeemed"), buyer, creator),
+            VoucherRedeemedEvent {
+                content_id,
+                nonce: voucher.nonce,
+                price: voucher.price,
+                fee,
+            },
+        );
+    }
+
+    /// Refund a direct purchase and revoke the buyer's access. `refunder`
+    /// must be the creator or the admin and must authorize.
+    ///
+    /// Whoever paid (the gifter, for gifts) gets back the creator's share
+    /// (`amount - fee`) from the creator, plus the platform fee from the fee
+    /// recipient when `refund_fee` is set; each must authorize its transfer.
+    /// Unsettled pre-orders are refunded in full from escrow instead. Clears any pending refund request.
+    /// Access the buyer holds through a bundle is unaffected.
+    ///
+    /// # Errors
+    /// - `NotRefunder` – `refunder` is neither the creator nor the admin.
+    /// - `NotRefundable` – no receipt for (buyer, creator, content_id).
+    pub fn refund_purchase(
+        env: Env,
+        refunder: Address,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+        refund_fee: bool,
+    ) {
+        require_refunder(&env, &refunder, &creator);
+        refunder.require_auth();
+
+        let receipt_key = DataKey::Receipt(buyer.clone(), creator.clone(), content_id);
+        let receipt: Receipt = env
+            .storage()
+            .instance()
+            .get(&receipt_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotRefundable));
+        let (amount, fee_refunded) =
+            if let Some(escrow) = preorder::remove_holder(&env, &creator, content_id, &buyer) {
+                // Unsettled pre-order: the full payment is still held by the contract.
+                if escrow.gross > 0 {
+                    token_client(&env).transfer(
+                        &env.current_contract_address(),
+                        &escrow.payer,
+                        &escrow.gross,
+                    );
+                }
+                (escrow.gross, escrow.fee)
+            } else {
+                // Each party returns what it was paid.
+                let token_client = token_client(&env);
+                let net = receipt.amount - receipt.fee;
+                if net > 0 {
+                    if refunder != creator {
+                        creator.require_auth();
+                    }
+                    token_client.transfer(&creator, &receipt.payer, &net);
+                }
+                let fee_refunded = if refund_fee { receipt.fee } else { 0 };
+                if fee_refunded > 0 {
+                    let fee_recipient = fee_recipient(&env);
+                    if fee_recipient != refunder && (net <= 0 || fee_recipient != creator) {
+                        fee_recipient.require_auth();
+                    }
+                    token_client.transfer(&fee_recipient, &receipt.payer, &fee_refunded);
+                }
+                (net + fee_refunded, fee_refunded)
+            };
+
+        env.storage().instance().remove(&receipt_key);
+        env.storage().instance().remove(&DataKey::Access(
+            buyer.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        release_delegations(&env, &buyer, &creator, content_id);
+        history::remove(&env, &buyer, &creator, content_id);
+        env.storage().instance().remove(&DataKey::RefundRequest(
+            buyer.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        env.storage().instance().remove(&DataKey::Listing(
+            buyer.clone(),
+            creator.clone(),
+            content_id,
+        ));
+
+        env.events().publish(
+            (Symbol::new(&env, "purchase_refunded"), buyer, creator),
+            PurchaseRefundedEvent {
+                content_id,
+                refunder,
+                amount,
+                fee_refunded,
+            },
+        );
+    }
+
+    /// Ask the creator or admin to refund a direct purchase. Buyer must authorize.
+    ///
+    /// Only allowed within `refund_window` ledgers of the purchase; the
+    /// request is settled by `refund_purchase` or dismissed by `decline_refund`.
+    ///
+    /// # Errors
+    /// - `NotRefundable` – no receipt for (buyer, creator, content_id).
+    /// - `RefundWindowClosed` – window unset or already elapsed.
+    pub fn request_refund(env: Env, buyer: Address, creator: Address, content_id: u64) {
+        buyer.require_auth();
+        let receipt: Receipt = env
+            .storage()
+            .instance()
+            .get(&DataKey::Receipt(
+                buyer.clone(),
+                creator.clone(),
+                content_id,
+            ))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotRefundable));
+        let window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RefundWindow)
+            .unwrap_or(0);
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        if current_seq >= receipt.purchased_at.saturating_add(window) {
+            panic_with_error!(&env, Error::RefundWindowClosed);
+        }
+
+        env.storage().instance().set(
+            &DataKey::RefundRequest(buyer.clone(), creator.clone(), content_id),
+            &current_seq,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "refund_requested"), buyer, creator),
+            content_id,
+        );
+    }
+
+    /// Dismiss a pending refund request without refunding. `refunder` must be
+    /// the creator or the admin and must authorize.
+    ///
+    /// # Errors
+    /// - `NotRefunder` – `refunder` is neither the creator nor the admin.
+    pub fn decline_refund(
+        env: Env,
+        refunder: Address,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+    ) {
+        require_refunder(&env, &refunder, &creator);
+        refunder.require_auth();
+        env.storage().instance().remove(&DataKey::RefundRequest(
+            buyer.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        env.events().publish(
+            (Symbol::new(&env, "refund_declined"), buyer, creator),
+            content_id,
+        );
+    }
+
+    /// Ledger at which the buyer requested a refund, if a request is pending.
+    pub fn refund_request(
+        env: Env,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+    ) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundRequest(buyer, creator, content_id))
+    }
+
+    /// Receipt for a direct purchase, if one is on record.
+    pub fn get_receipt(
+        env: Env,
+        buyer: Address,
+        creator: Address,
+        content_id: u64,
+    ) -> Option<Receipt> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Receipt(buyer, creator, content_id))
+    }
+
+    /// Set how many ledgers after purchase buyers may request a refund
+    /// (0 disables requests). Only admin may call this.
+    pub fn set_refund_window(env: Env, ledgers: u64) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundWindow, &ledgers);
+    }
+
+    /// Get the buyer refund request window in ledgers (0 when disabled).
+    pub fn refund_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundWindow)
+            .unwrap_or(0)
+    }
+
+    /// Add a piece of content to the creator's catalog as active. Creator must authorize.
+    ///
+    /// The price is validated like `set_content_price` and mirrored into the
+    /// legacy price key so `get_content_price` keeps working.
+    ///
+    /// # Errors
+    /// - `ContentAlreadyRegistered` – (creator, content_id) is already in the catalog.
+    /// - `InvalidPrice` / price rule errors – see `set_content_price`.
+    pub fn register_content(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        price: i128,
+        metadata_hash: BytesN<32>,
+    ) {
+        creator.require_auth();
+        let key = DataKey::Content(creator.clone(), content_id);
+        if env.storage().instance().has(&key) {
+            panic_with_error!(&env, Error::ContentAlreadyRegistered);
+        }
+        require_valid_price(&env, &creator, Some(content_id), price);
+
+        let info = ContentInfo {
+            price,
+            is_active: true,
+            metadata_hash: metadata_hash.clone(),
+        };
+        env.storage().instance().set(&key, &info);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContentPrice(creator.clone(), content_id), &price);
+
+        sync_catalog_price(&env, &creator, content_id);
+        catalog::add(&env, &creator, content_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "content_registered"), creator.clone()),
+            ContentRegisteredEvent {
+                creator,
+                content_id,
+                price,
+                metadata_hash,
+            },
+        );
+    }
+
+    /// Make a deactivated catalog entry purchasable again. Creator must authorize.
+    pub fn activate_content(env: Env, creator: Address, content_id: u64) {
+        set_content_status(&env, creator, content_id, true);
+    }
+
+    /// Stop new purchases of a catalog entry. Creator must authorize.
+    ///
+    /// Existing purchases keep their access until they expire.
+    pub fn deactivate_content(env: Env, creator: Address, content_id: u64) {
+        set_content_status(&env, creator, content_id, false);
+    }
+
+    /// Get the catalog entry for (creator, content_id), if registered.
+    pub fn get_content(env: Env, creator: Address, content_id: u64) -> Option<ContentInfo> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Content(creator, content_id))
+    }
+
+    /// Paginated list of a creator's registered content IDs, in registration order.
+    ///
+    /// # Arguments
+    /// * `cursor` - Index to start from (0 for first page)
+    /// * `limit` - Max number of items to return (capped at MAX_PAGE_LIMIT)
+    ///
+    /// # Returns
+    /// (page of content_ids, next_cursor) — `next_cursor` is 0 when there is no next page
+    pub fn list_content(env: Env, creator: Address, cursor: u32, limit: u32) -> (Vec<u64>, u32) {
+        catalog::page(
+            &env,
+            &creator,
+            cursor,
+            core::cmp::min(limit, MAX_PAGE_LIMIT),
+        )
+    }
+
+    /// Paginated purchase history of `buyer` in purchase order, with each
+    /// purchase's current expiry and the price paid. Expired purchases stay
+    /// listed; refunded and resold ones are dropped, their slot taken by the
+    /// buyer's most recent purchase. Content added to a bundle after it was
+    /// bought is not listed.
+    ///
+    /// # Returns
+    /// (page of purchases, next_cursor) — `next_cursor` is 0 when there is no next page
+    pub fn list_purchases_by_buyer(
+        env: Env,
+        buyer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<PurchaseRecord>, u32) {
+        let limit = core::cmp::min(limit, MAX_PAGE_LIMIT);
+        let len = history::purchase_count(&env, &buyer);
+        history::page(&env, len, cursor, limit, |i| {
+            let (creator, content_id) = history::purchase_at(&env, &buyer, i)?;
+            Some(history::record(&env, &buyer, &creator, content_id))
+        })
+    }
+
+    /// Paginated holders of (creator, content_id) in purchase order, with
+    /// the same fields and ordering rules as `list_purchases_by_buyer`.
+    pub fn list_buyers_by_content(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<PurchaseRecord>, u32) {
+        let limit = core::cmp::min(limit, MAX_PAGE_LIMIT);
+        let len = history::buyer_count(&env, &creator, content_id);
+        history::page(&env, len, cursor, limit, |i| {
+            let buyer = history::buyer_at(&env, &creator, content_id, i)?;
+            Some(history::record(&env, &buyer, &creator, content_id))
+        })
+    }
+
+    /// Limit (creator, content_id) to `cap` permanent copies, each numbered
+    /// with a serial. Creator must authorize. Copies sold before the cap was
+    /// set count towards it but keep no serial. Once sales begin the cap can
+    /// only be lowered, and never below the copies already issued.
+    ///
+    /// Rentals are not copies: they are never counted against the cap and
+    /// stay on sale after the item sells out.
+    ///
+    /// # Errors
+    /// - `InvalidSupplyCap` – `cap` is zero, below the issued copies, raises
+    ///   the cap after the first sale, or the content belongs to a bundle.
+    pub fn set_supply_cap(env: Env, creator: Address, content_id: u64, cap: u32) {
+        creator.require_auth();
+        let issued = supply::issued(&env, &creator, content_id);
+        let raised = supply::cap(&env, &creator, content_id).is_some_and(|current| cap > current);
+        if cap == 0
+            || cap < issued
+            || (issued > 0 && raised)
+            || bundle::is_member(&env, &creator, content_id)
+        {
+            panic_with_error!(&env, Error::InvalidSupplyCap);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::SupplyCap(creator.clone(), content_id), &cap);
+        env.events().publish(
+            (Symbol::new(&env, "supply_cap_set"), creator),
+            (content_id, cap),
+        );
+    }
+
+    /// Permanent copies of (creator, content_id) still available, or `None`
+    /// if the content is not a limited edition.
+    pub fn get_remaining_supply(env: Env, creator: Address, content_id: u64) -> Option<u32> {
+        supply::cap(&env, &creator, content_id)
+            .map(|cap| cap.saturating_sub(supply::issued(&env, &creator, content_id)))
+    }
+
+    /// Serial of `buyer`'s copy of (creator, content_id), if they hold a
+    /// numbered limited-edition purchase.
+    pub fn get_serial(env: Env, buyer: Address, creator: Address, content_id: u64) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get::<DataKey, Purchase>(&DataKey::Access(buyer, creator, content_id))
+            .and_then(|p| p.serial)
+    }
+
+    /// Enable resale of permanent purchases of (creator, content_id) with a
+    /// royalty of `royalty_bps` on every sale, or disable it with `None`.
+    /// Creator must authorize. While disabled, existing listings cannot be bought.
+    ///
+    /// # Errors
+    /// - `InvalidFeeBps` – `royalty_bps` exceeds 10_000.
+    pub fn set_resale_royalty(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        royalty_bps: Option<u32>,
+    ) {
+        creator.require_auth();
+        let key = DataKey::ResaleRoyalty(creator.clone(), content_id);
+        match royalty_bps {
+            Some(bps) => {
+                require_valid_fee_bps(&env, bps);
+                env.storage().instance().set(&key, &bps);
+            }
+            None => env.storage().instance().remove(&key),
+        }
+        env.events().publish(
+            (Symbol::new(&env, "resale_configured"), creator),
+            (content_id, royalty_bps),
+        );
+    }
+
+    /// Creator royalty on resales of (creator, content_id); `None` when resale is disabled.
+    pub fn get_resale_royalty(env: Env, creator: Address, content_id: u64) -> Option<u32> {
+        resale::royalty_bps(&env, &creator, content_id)
+    }
+
+    /// Offer `seller`'s permanent purchase of (creator, content_id) for
+    /// `price`, replacing any previous listing. Seller must authorize and
+    /// keeps access until the listing is bought.
+    ///
+    /// # Errors
+    /// - `ResaleDisabled` – creator has not enabled resale.
+    /// - `NotYetReleased` – content is an unreleased pre-order.
+    /// - `NotResellable` – no permanent direct purchase, access is held
+    ///   through a bundle, or the seller's pre-order is not yet settled.
+    /// - `InvalidPrice` – `price` is not positive.
+    pub fn list_access(env: Env, seller: Address, creator: Address, content_id: u64, price: i128) {
+        seller.require_auth();
+        resale::require_resellable(&env, &seller, &creator, content_id);
+        if price <= 0 {
+            panic_with_error!(&env, Error::InvalidPrice);
+        }
+        env.storage().instance().set(
+            &DataKey::Listing(seller.clone(), creator.clone(), content_id),
+            &price,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "access_listed"), seller, creator),
+            (content_id, price),
+        );
+    }
+
+    /// Withdraw `seller`'s listing of (creator, content_id). Seller must authorize.
+    ///
+    /// # Errors
+    /// - `ListingNotFound` – nothing is listed.
+    pub fn cancel_listing(env: Env, seller: Address, creator: Address, content_id: u64) {
+        seller.require_auth();
+        let key = DataKey::Listing(seller.clone(), creator.clone(), content_id);
+        if !env.storage().instance().has(&key) {
+            panic_with_error!(&env, Error::ListingNotFound);
+        }
+        env.storage().instance().remove(&key);
+        env.events().publish(
+            (Symbol::new(&env, "listing_cancelled"), seller, creator),
+            content_id,
+        );
+    }
+
+    /// Asking price of `seller`'s listing of (creator, content_id), if any.
+    pub fn get_listing(
+        env: Env,
+        seller: Address,
+        creator: Address,
+        content_id: u64,
+    ) -> Option<i128> {
+        resale::listing(&env, &seller, &creator, content_id)
+    }
+
+    /// Buy `seller`'s listed purchase of (creator, content_id). Buyer must authorize.
+    ///
+    /// The creator receives the resale royalty and the seller the rest. The
+    /// `Access` record, serial included, moves to the buyer; the seller's
+    /// receipt and delegations are dropped. Resales are not refundable.
+    ///
+    /// # Errors
+    /// - `Paused` – contract is paused.
+    /// - `ListingNotFound` – nothing is listed.
+    /// - `ResaleDisabled` / `NotResellable` – the listing is no longer valid.
+    /// - `ContentModerated` – content is suspended or removed.
+    /// - `AlreadyHasAccess` – buyer already holds a valid purchase.
+    pub fn buy_resale(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        creator: Address,
+        content_id: u64,
+    ) {
+        if env.storage().instance().has(&DataKey::Paused) {
+            panic_with_error!(&env, Error::Paused);
+        }
+        buyer.require_auth();
+        let price = resale::listing(&env, &seller, &creator, content_id)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ListingNotFound));
+        let purchase = resale::require_resellable(&env, &seller, &creator, content_id);
+        moderation::require_purchasable(&env, &creator, content_id);
+        registry::require_registered(&env, &creator);
+        attestation::require_allowed(&env, &buyer, &creator, content_id);
+        preorder::require_not_pending(&env, &creator, content_id, &buyer);
+
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        let buyer_key = DataKey::Access(buyer.clone(), creator.clone(), content_id);
+        if env
+            .storage()
+            .instance()
+            .get::<DataKey, Purchase>(&buyer_key)
+            .is_some_and(|p| p.expiry > current_seq)
+        {
+            panic_with_error!(&env, Error::AlreadyHasAccess);
+        }
+
+        let royalty_bps = resale::royalty_bps(&env, &creator, content_id).unwrap_or(0);
+        let royalty = price * royalty_bps as i128 / 10_000;
+        let token_client = token_client(&env);
+        if royalty > 0 {
+            token_client.transfer(&buyer, &creator, &royalty);
+        }
+        if price - royalty > 0 {
+            token_client.transfer(&buyer, &seller, &(price - royalty));
+        }
+
+        for holder in [&seller, &buyer] {
+            env.storage().instance().remove(&DataKey::Receipt(
+                holder.clone(),
+                creator.clone(),
+                content_id,
+            ));
+            env.storage().instance().remove(&DataKey::RefundRequest(
+                holder.clone(),
+                creator.clone(),
+                content_id,
+            ));
+        }
+        env.storage().instance().remove(&DataKey::Listing(
+            seller.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        env.storage().instance().remove(&DataKey::Access(
+            seller.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        delegation::clear(&env, &seller, &creator, content_id);
+        history::remove(&env, &seller, &creator, content_id);
+        env.storage().instance().set(&buyer_key, &purchase);
+        history::add(&env, &buyer, &creator, content_id, price);
+
+        env.events().publish(
+            (Symbol::new(&env, "access_resold"), seller, buyer),
+            AccessResoldEvent {
+                creator,
+                content_id,
+                price,
+                royalty,
+            },
+        );
+    }
+
+    /// Set how many addresses each buyer of (creator, content_id) may share
+    /// access with. Creator must authorize. Lowering the limit keeps existing
+    /// delegates but blocks new ones.
+    ///
+    /// # Errors
+    /// - `DelegationLimitReached` – `limit` exceeds `MAX_SHARE_LIMIT`.
+    pub fn set_share_limit(env: Env, creator: Address, content_id: u64, limit: u32) {
+        creator.require_auth();
+        if limit > delegation::MAX_SHARE_LIMIT {
+            panic_with_error!(&env, Error::DelegationLimitReached);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ShareLimit(creator.clone(), content_id), &limit);
+        env.events().publish(
+            (Symbol::new(&env, "share_limit_set"), creator),
+            (content_id, limit),
+        );
+    }
+
+    /// Delegates allowed per purchase of (creator, content_id); 0 disables sharing.
+    pub fn get_share_limit(env: Env, creator: Address, content_id: u64) -> u32 {
+        delegation::share_limit(&env, &creator, content_id)
+    }
+
+    /// Share `owner`'s purchase of (creator, content_id) with `delegate`. Owner must authorize.
+    ///
+    /// The delegate's access follows the owner's own (direct or bundle)
+    /// purchase and ends with it. Changes are rate-limited to one per
+    /// `DELEGATION_COOLDOWN_LEDGERS`.
+    ///
+    /// # Errors
+    /// - `NotBuyer` / `PurchaseExpired` – owner holds no valid purchase.
+    /// - `AlreadyDelegated` – delegate is the owner or already has a delegation.
+    /// - `DelegationLimitReached` – owner already shares with the creator's limit.
+    /// - `DelegationCooldown` – previous change was too recent.
+    pub fn delegate_access(
+        env: Env,
+        owner: Address,
+        creator: Address,
+        content_id: u64,
+        delegate: Address,
+    ) {
+        owner.require_auth();
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        match own_expiry(&env, &owner, &creator, content_id) {
+            None => panic_with_error!(&env, Error::NotBuyer),
+            Some(expiry) if expiry <= current_seq => {
+                panic_with_error!(&env, Error::PurchaseExpired)
+            }
+            Some(_) => {}
+        }
+        if delegate == owner {
+            panic_with_error!(&env, Error::AlreadyDelegated);
+        }
+        if let Some(previous) = delegation::owner_of(&env, &delegate, &creator, content_id) {
+            // A link to an owner whose purchase has lapsed is stale; drop it.
+            release_delegations(&env, &previous, &creator, content_id);
+            if delegation::owner_of(&env, &delegate, &creator, content_id).is_some() {
+                panic_with_error!(&env, Error::AlreadyDelegated);
+            }
+        }
+        let mut delegates = delegation::delegates(&env, &owner, &creator, content_id);
+        if delegates.len() >= delegation::share_limit(&env, &creator, content_id) {
+            panic_with_error!(&env, Error::DelegationLimitReached);
+        }
+        delegation::start_cooldown(&env, &owner, &creator, content_id);
+
+        delegates.push_back(delegate.clone());
+        delegation::set_delegates(&env, &owner, &creator, content_id, &delegates);
+        env.storage().instance().set(
+            &DataKey::DelegatedFrom(delegate.clone(), creator.clone(), content_id),
+            &owner,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "access_delegated"), owner, delegate),
+            (creator, content_id),
+        );
+    }
+
+    /// Stop sharing `owner`'s purchase of (creator, content_id) with
+    /// `delegate`. Owner must authorize; subject to the same cooldown.
+    ///
+    /// # Errors
+    /// - `NotBuyer` – `delegate` is not one of the owner's delegates.
+    /// - `DelegationCooldown` – previous change was too recent.
+    pub fn revoke_delegation(
+        env: Env,
+        owner: Address,
+        creator: Address,
+        content_id: u64,
+        delegate: Address,
+    ) {
+        owner.require_auth();
+        let mut delegates = delegation::delegates(&env, &owner, &creator, content_id);
+        let index = delegates
+            .first_index_of(&delegate)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotBuyer));
+        delegation::start_cooldown(&env, &owner, &creator, content_id);
+
+        delegates.remove(index);
+        delegation::set_delegates(&env, &owner, &creator, content_id, &delegates);
+        env.storage().instance().remove(&DataKey::DelegatedFrom(
+            delegate.clone(),
+            creator.clone(),
+            content_id,
+        ));
+        env.events().publish(
+            (Symbol::new(&env, "delegation_revoked"), owner, delegate),
+            (creator, content_id),
+        );
+    }
+
+    /// Addresses `owner` currently shares (creator, content_id) with.
+    pub fn get_delegates(
+        env: Env,
+        owner: Address,
+        creator: Address,
+        content_id: u64,
+    ) -> Vec<Address> {
+        delegation::delegates(&env, &owner, &creator, content_id)
+    }
+
+    /// Schedule (creator, content_id) for release at `release_ledger`. Creator must authorize.
+    ///
+    /// Until then purchases are pre-orders: payment is escrowed and
+    /// `has_access` is false. Moving an existing release later emits
+    /// `release_postponed`; otherwise `release_scheduled`.
+    ///
+    /// # Errors
+    /// - `InvalidReleaseLedger` – `release_ledger` is not in the future.
+    /// - `AlreadyReleased` – the current release ledger has passed, or the
+    ///   item was never scheduled and already has buyers.
+    /// - `RefundsPending` – a cancelled release still has pre-orders to refund.
+    pub fn set_release_ledger(env: Env, creator: Address, content_id: u64, release_ledger: u64) {
+        creator.require_auth();
+        let current_seq: u64 = env.ledger().sequence() as u64;
+        if release_ledger <= current_seq {
+            panic_with_error!(&env, Error::InvalidReleaseLedger);
+        }
+        let previous = preorder::release_ledger(&env, &creator, content_id);
+        let already_sold = match previous {
+            Some(release) => release <= current_seq,
+            None => history::buyer_count(&env, &creator, content_id) > 0,
+        };
+        if already_sold {
+            panic_with_error!(&env, Error::AlreadyReleased);
+        }
+        if previous == Some(u64::MAX) && preorder::count(&env, &creator, content_id) > 0 {
+            panic_with_error!(&env, PreOrderError::RefundsPending);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ReleaseLedger(creator.clone(), content_id),
+            &release_ledger,
+        );
+        let topic = if previous.is_some_and(|release| release_ledger > release) {
+            "release_postponed"
+        } else {
+            "release_scheduled"
+        };
+        env.events().publish(
+            (Symbol::new(&env, topic), creator),
+            ReleaseScheduledEvent {
+                content_id,
+                previous,
+                release_ledger,
+            },
+        );
+    }
+
+    /// Release ledger of (creator, content_id), if it was sold as a pre-order.
+    pub fn get_release_ledger(env: Env, creator: Address, content_id: u64) -> Option<u64> {
+        preorder::release_ledger(&env, &creator, content_id)
+    }
 
-#[contractimpl]
-impl ContentAccess {
-    /// Initialize the contract with admin and token address
-    pub fn initialize(env: Env, admin: Address, token_address: Address) {
-        admin.require_auth();
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic_with_error!(&env, Error::AlreadyInitialized);
-        }
+    /// Holders whose pre-order payments for (creator, content_id) are still escrowed.
+    pub fn get_preorders(env: Env, creator: Address, content_id: u64) -> Vec<Address> {
+        preorder::holders(&env, &creator, content_id)
+    }
 
-        let token_client = token::Client::new(&env, &token_address);
-        let _ = token_client.balance(&admin);
+    /// Pay out escrowed pre-orders once (creator, content_id) is released:
+    /// fees to the fee recipient, the rest to the creator. Anyone may call this.
+    ///
+    /// Pays out at most `limit` (capped at 50) pre-orders, oldest first; call
+    /// again until it returns 0 to settle the rest.
+    ///
+    /// Returns the number of pre-orders still escrowed.
+    ///
+    /// # Errors
+    /// - `NotYetReleased` – release ledger not reached.
+    pub fn settle_preorders(env: Env, creator: Address, content_id: u64, limit: u32) -> u32 {
+        if preorder::is_unreleased(&env, &creator, content_id) {
+            panic_with_error!(&env, Error::NotYetReleased);
+        }
+        let limit = core::cmp::min(limit, preorder::MAX_PREORDER_BATCH);
+        let escrows = preorder::take(&env, &creator, content_id, limit);
+        let mut gross: i128 = 0;
+        let mut fee: i128 = 0;
+        for escrow in escrows.iter() {
+            gross += escrow.gross;
+            fee += escrow.fee;
+        }
 
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::TokenAddress, &token_address);
+        let token_client = token_client(&env);
+        let contract = env.current_contract_address();
+        if fee > 0 {
+            token_client.transfer(&contract, &fee_recipient(&env), &fee);
+        }
+        if gross - fee > 0 {
+            token_client.transfer(&contract, &creator, &(gross - fee));
+        }
 
+        let remaining = preorder::count(&env, &creator, content_id);
         env.events().publish(
-            (Symbol::new(&env, "initialized"),),
-            InitializedEvent {
-                admin: admin.clone(),
+            (Symbol::new(&env, "preorders_settled"), creator),
+            PreOrdersSettledEvent {
+                content_id,
+                count: escrows.len(),
+                gross,
+                fee,
+                remaining,
             },
         );
+        remaining
     }
 
-    /// Unlock content for a buyer by transferring payment to creator.
+    /// Cancel an upcoming release and refund escrowed pre-orders in full.
+    /// Creator must authorize.
+    ///
+    /// Refunds at most `limit` (capped at 50) pre-orders, oldest first; call
+    /// again until it returns 0 to refund the rest. The item stays unreleased
+    /// (release ledger `u64::MAX`) and cannot be rescheduled until every
+    /// pre-order is refunded.
     ///
-    /// `expiry_ledger` sets when the purchase expires. Pass `u64::MAX` for a
-    /// non-expiring purchase. Passing a value at or below the current ledger
-    /// sequence is rejected with [`Error::InvalidExpiry`].
+    /// Returns the number of pre-orders still awaiting refund.
     ///
     /// # Errors
-    /// - `ContentPriceNotSet` – no price registered for (creator, content_id).
+    /// - `NotYetReleased` – no release is scheduled.
+    /// - `AlreadyReleased` – the release ledger has passed.
+    pub fn cancel_release(env: Env, creator: Address, content_id: u64, limit: u32) -> u32 {
+        creator.require_auth();
+        if preorder::release_ledger(&env, &creator, content_id).is_none() {
+            panic_with_error!(&env, Error::NotYetReleased);
+        }
+        if !preorder::is_unreleased(&env, &creator, content_id) {
+            panic_with_error!(&env, Error::AlreadyReleased);
+        }
+
+        let limit = core::cmp::min(limit, preorder::MAX_PREORDER_BATCH);
+        let escrows = preorder::take(&env, &creator, content_id, limit);
+        let token_client = token_client(&env);
+        let contract = env.current_contract_address();
+        for escrow in escrows.iter() {
+            let holder = escrow.holder;
+            if escrow.gross > 0 {
+                token_client.transfer(&contract, &escrow.payer, &escrow.gross);
+            }
+            env.storage().instance().remove(&DataKey::Receipt(
+                holder.clone(),
+                creator.clone(),
+                content_id,
+            ));
+            env.storage().instance().remove(&DataKey::Access(
+                holder.clone(),
+                creator.clone(),
+                content_id,
+            ));
+            release_delegations(&env, &holder, &creator, content_id);
+            history::remove(&env, &holder, &creator, content_id);
+            env.storage().instance().remove(&DataKey::RefundRequest(
+                holder.clone(),
+                creator.clone(),
+                content_id,
+            ));
+            env.events().publish(
+                (
+                    Symbol::new(&env, "purchase_refunded"),
+                    holder,
+                    creator.clone(),
+                ),
+                PurchaseRefundedEvent {
+                    content_id,
+                    refunder: contract.clone(),
+                    amount: escrow.gross,
+                    fee_refunded: escrow.fee,
+                },
+            );
+        }
+
+        env.storage().instance().set(
+            &DataKey::ReleaseLedger(creator.clone(), content_id),
+            &u64::MAX,
+        );
+        let remaining = preorder::count(&env, &creator, content_id);
+        env.events().publish(
+            (Symbol::new(&env, "release_cancelled"), creator),
+            (content_id, escrows.len(), remaining),
+        );
+        remaining
+    }
+
+    /// Set the moderation state of (creator, content_id). Only admin may call this.
     ///
-    /// # Panics (auth)
-    /// - Buyer must authorize the transaction.
-    pub fn unlock_content(
+    /// `Suspended` and `Removed` block new purchases (direct, gift, voucher
+    /// and bundle); `Removed` also revokes access for existing buyers.
+    /// Setting `Active` lifts the action. Refunds are not automatic; issue
+    /// them with `refund_purchase`.
+    pub fn moderate_content(
         env: Env,
-        buyer: Address,
         creator: Address,
         content_id: u64,
-        expiry_ledger: u64,
+        status: ModerationStatus,
+        reason: u32,
     ) {
-        if env.storage().instance().has(&DataKey::Paused) {
-            panic_with_error!(&env, Error::Paused);
-        }
-        buyer.require_auth();
-
-        // Cache current ledger sequence once (hot path optimization).
-        let current_seq: u64 = env.ledger().sequence() as u64;
-
-        // #1385: Reject expiry at or below the current ledger — such a purchase
-        // would be immediately expired and is almost certainly a caller mistake.
-        if expiry_ledger <= current_seq {
-            panic_with_error!(&env, Error::InvalidExpiry);
-        }
-
-        // Check if already unlocked (idempotent) – but re-check expiry.
-        let access_key = DataKey::Access(buyer.clone(), creator.clone(), content_id);
-        if let Some(existing) = env
+        let admin: Address = env
             .storage()
             .instance()
-            .get::<DataKey, Purchase>(&access_key)
-        {
-            // If the existing purchase is still valid, treat as no-op.
-            if existing.expiry > current_seq {
-                return;
-            }
-            // Expired purchase: allow re-purchase by falling through.
-        }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+
+        let previous = moderation::status(&env, &creator, content_id);
+        env.storage().instance().set(
+            &DataKey::Moderation(creator.clone(), content_id),
+            &ModerationRecord {
+                status,
+                reason,
+                updated_at: env.ledger().sequence() as u64,
+            },
+        );
+        env.events().publish(
+            (Symbol::new(&env, "content_moderated"), creator),
+            ContentModeratedEvent {
+                content_id,
+                previous,
+                status,
+                reason,
+                moderator: admin,
+            },
+        );
+    }
 
-        // Get stored price
-        let price: i128 = Self::get_content_price(env.clone(), creator.clone(), content_id)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::ContentPriceNotSet));
+    /// Latest moderation action on (creator, content_id), if any.
+    pub fn get_moderation(env: Env, creator: Address, content_id: u64) -> Option<ModerationRecord> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Moderation(creator, content_id))
+    }
 
-        // Get token address
-        let token_address: Address = env
+    /// Update the platform fee on sales (admin only). `new_fee_bps` must be <= 10_000.
+    ///
+    /// Emits `fee_updated` with data `(old_bps, new_bps)`.
+    pub fn set_fee_bps(env: Env, new_fee_bps: u32) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::TokenAddress)
-            .unwrap();
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
 
-        // Transfer tokens from buyer to creator
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&buyer, &creator, &price);
+        require_valid_fee_bps(&env, new_fee_bps);
 
-        // Store purchase record with expiry
-        let purchase = Purchase {
-            expiry: expiry_ledger,
-        };
-        env.storage().instance().set(&access_key, &purchase);
+        let old: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
+        env.storage().instance().set(&DataKey::FeeBps, &new_fee_bps);
 
-        // Emit event (construct symbol once)
-        let topic = Symbol::new(&env, "content_unlocked");
         env.events()
-            .publish((topic, buyer.clone(), creator.clone()), (content_id, price));
+            .publish((Symbol::new(&env, "fee_updated"),), (old, new_fee_bps));
     }
 
-    /// Check if buyer has valid (non-expired) access to content.
-    pub fn has_access(env: Env, buyer: Address, creator: Address, content_id: u64) -> bool {
-        let access_key = DataKey::Access(buyer, creator, content_id);
-        if let Some(purchase) = env
+    /// Update the platform fee on tips (admin only), independent of the sale
+    /// fee. `new_fee_bps` must be <= 10_000; defaults to 0.
+    ///
+    /// Emits `tip_fee_updated` with data `(old_bps, new_bps)`.
+    pub fn set_tip_fee_bps(env: Env, new_fee_bps: u32) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get::<DataKey, Purchase>(&access_key)
-        {
-            let current_seq: u64 = env.ledger().sequence() as u64;
-            purchase.expiry > current_seq
-        } else {
-            false
-        }
-    }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
 
-    /// Verify that `claimer` is the buyer of (creator, content_id) and the purchase
-    /// is not expired.
-    ///
-    /// # Panics (contract errors)
-    /// - `NotBuyer`        – no purchase record exists for `claimer`.
-    /// - `PurchaseExpired` – purchase exists but has expired.
-    pub fn verify_access(env: Env, claimer: Address, creator: Address, content_id: u64) {
-        let access_key = DataKey::Access(claimer.clone(), creator.clone(), content_id);
-        let purchase: Purchase = env
-            .storage()
+        require_valid_fee_bps(&env, new_fee_bps);
+
+        let old = tips::fee_bps(&env);
+        env.storage()
             .instance()
-            .get::<DataKey, Purchase>(&access_key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::NotBuyer));
+            .set(&DataKey::TipFeeBps, &new_fee_bps);
 
-        let current_seq: u64 = env.ledger().sequence() as u64;
-        if purchase.expiry <= current_seq {
-            panic_with_error!(&env, Error::PurchaseExpired);
-        }
+        env.events()
+            .publish((Symbol::new(&env, "tip_fee_updated"),), (old, new_fee_bps));
     }
 
-    /// Get the price for (creator, content_id). Returns None if not set.
-    pub fn get_content_price(env: Env, creator: Address, content_id: u64) -> Option<i128> {
-        let key = DataKey::ContentPrice(creator.clone(), content_id);
-        env.storage().instance().get(&key)
+    /// Platform fee on tips in basis points.
+    pub fn tip_fee_bps(env: Env) -> u32 {
+        tips::fee_bps(&env)
     }
 
-    /// Set the price for a creator's content. Creator must authorize.
-    pub fn set_content_price(env: Env, creator: Address, content_id: u64, price: i128) {
-        creator.require_auth();
+    /// Rotate the platform fee recipient (admin only).
+    ///
+    /// Rejects the Stellar null / burn strkey (`GAAA...WHF`). Emits
+    /// `fee_recipient_updated` with topics `(fee_recipient_updated, old_recipient, new_recipient)`.
+    pub fn set_fee_recipient(env: Env, new_fee_recipient: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
 
-        if price <= 0 {
-            panic_with_error!(&env, Error::InvalidPrice);
-        }
+        require_valid_fee_recipient(&env, &new_fee_recipient);
 
-        if let Some(max_price) = env
-            .storage()
+        let old = fee_recipient(&env);
+        env.storage()
             .instance()
-            .get::<DataKey, i128>(&DataKey::MaxPrice)
-        {
-            if price > max_price {
-                panic_with_error!(&env, Error::PriceExceedsMax);
-            }
-        }
+            .set(&DataKey::FeeRecipient, &new_fee_recipient);
 
-        let key = DataKey::ContentPrice(creator.clone(), content_id);
-        env.storage().instance().set(&key, &price);
         env.events().publish(
-            (Symbol::new(&env, "content_price_set"), creator.clone()),
-            ContentPriceSetEvent {
-                creator,
-                content_id,
-                price,
-            },
+            (
+                Symbol::new(&env, "fee_recipient_updated"),
+                old,
+                new_fee_recipient,
+            ),
+            (),
         );
     }
 
+    /// Platform fee on sales in basis points.
+    pub fn fee_bps(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+    }
+
+    /// Address receiving platform fees; defaults to the admin.
+    pub fn fee_recipient(env: Env) -> Address {
+        fee_recipient(&env)
+    }
+
     /// Set a global maximum price cap. Only admin may call this.
     /// Pass `0` to remove the cap entirely.
     pub fn set_max_price(env: Env, max_price: i128) {
@@ -285,6 +2846,12 @@ impl ContentAccess {
             if max_price < 0 {
                 panic_with_error!(&env, Error::InvalidMaxPrice);
             }
+            if pricing::rule(&env, &PriceScope::Global)
+                .min_price
+                .is_some_and(|min| max_price < min)
+            {
+                panic_with_error!(&env, Error::InvalidPriceRule);
+            }
             env.storage().instance().set(&DataKey::MaxPrice, &max_price);
             env.events().publish(
                 (Symbol::new(&env, "max_price_set"), admin.clone()),
@@ -301,6 +2868,110 @@ impl ContentAccess {
         env.storage().instance().get(&DataKey::MaxPrice)
     }
 
+    /// Set the price floor and cap for a scope. Only admin may call this.
+    ///
+    /// `PriceScope::Global` replaces the global cap (see `set_max_price`) and
+    /// floor. A rule with neither bound removes the scope's rule. Prices
+    /// already set are not changed; see `get_price_violation`.
+    ///
+    /// # Errors
+    /// - `InvalidPriceRule` – a bound is not positive, or the floor is above the cap.
+    pub fn set_price_rule(env: Env, scope: PriceScope, rule: PriceRule) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        pricing::require_valid_rule(&env, &rule);
+        pricing::set_rule(&env, &scope, &rule);
+        env.events()
+            .publish((Symbol::new(&env, "price_rule_set"), admin), (scope, rule));
+    }
+
+    /// Bounds configured for `scope`; unset bounds are `None`.
+    pub fn get_price_rule(env: Env, scope: PriceScope) -> PriceRule {
+        pricing::rule(&env, &scope)
+    }
+
+    /// Assign a creator's trust level for price rules. Only admin may call
+    /// this. Creators start at level 0.
+    pub fn set_trust_level(env: Env, creator: Address, level: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::TrustLevel(creator.clone()), &level);
+        env.events()
+            .publish((Symbol::new(&env, "trust_level_set"), creator), level);
+    }
+
+    /// Creator's trust level for price rules (0 when unassigned).
+    pub fn get_trust_level(env: Env, creator: Address) -> u32 {
+        pricing::trust_level(&env, &creator)
+    }
+
+    /// Tag (creator, content_id) with a platform-defined category code, or
+    /// clear it with `None`. Creator must authorize. Existing prices are not
+    /// re-checked; see `get_price_violation`.
+    pub fn set_content_category(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+        category: Option<u32>,
+    ) {
+        creator.require_auth();
+        let key = DataKey::ContentCategory(creator.clone(), content_id);
+        match category {
+            Some(code) => env.storage().instance().set(&key, &code),
+            None => env.storage().instance().remove(&key),
+        }
+        env.events().publish(
+            (Symbol::new(&env, "content_category_set"), creator),
+            (content_id, category),
+        );
+    }
+
+    /// Category code of (creator, content_id), if tagged.
+    pub fn get_content_category(env: Env, creator: Address, content_id: u64) -> Option<u32> {
+        pricing::category(&env, &creator, content_id)
+    }
+
+    /// First price rule that a current price of (creator, content_id)
+    /// breaks, e.g. after a cap was lowered, checking every access option.
+    pub fn get_price_violation(
+        env: Env,
+        creator: Address,
+        content_id: u64,
+    ) -> Option<PriceViolation> {
+        price_violation(&env, &creator, content_id)
+    }
+
+    /// Paginated list of a creator's registered content IDs whose prices
+    /// break a price rule, scanning the catalog like `list_content`.
+    ///
+    /// # Returns
+    /// (flagged content_ids in the scanned page, next_cursor) — `next_cursor` is 0 when there is no next page
+    pub fn list_price_violations(
+        env: Env,
+        creator: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<u64>, u32) {
+        let (page, next_cursor) = Self::list_content(env.clone(), creator.clone(), cursor, limit);
+        let mut flagged = Vec::new(&env);
+        for content_id in page.iter() {
+            if price_violation(&env, &creator, content_id).is_some() {
+                flagged.push_back(content_id);
+            }
+        }
+        (flagged, next_cursor)
+    }
+
     /// Pause or unpause the contract. Only admin may call this.
     ///
     /// When paused, `unlock_content` is blocked (emergency stop).
@@ -379,8 +3050,23 @@ mod test {
         }
     }
 
-    /// Default expiry: far future (non-expiring purchase).
-    const NO_EXPIRY: u64 = u64::MAX;
+    /// Option index of the default lifetime option for unscheduled content.
+    const LIFETIME: u32 = 0;
+
+    /// Replace (creator, content_id)'s schedule with a single rental option.
+    fn set_rental(client: &ContentAccessClient, creator: &Address, content_id: u64, ledgers: u64) {
+        client.set_access_options(
+            creator,
+            &content_id,
+            &soroban_sdk::vec![
+                &client.env,
+                AccessOption {
+                    duration_ledgers: Some(ledgers),
+                    price: 100,
+                },
+            ],
+        );
+    }
 
     fn setup_test() -> (Env, Address, Address, Address, Address, Address) {
         let env = Env::default();
@@ -424,7 +3110,7 @@ mod test {
         assert!(!client.has_access(&buyer, &creator, &1));
 
         client.set_content_price(&creator, &1, &100);
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
 
@@ -438,7 +3124,7 @@ mod test {
                         creator.clone(),
                     )
                         .into_val(&env)
-                && event.2.try_into_val(&env).ok() == Some((1u64, 100i128))
+                && event.2.try_into_val(&env).ok() == Some((1u64, 100i128, 100i128, 0i128, 100i128))
         }));
     }
 
@@ -460,7 +3146,7 @@ mod test {
         client.set_content_price(&creator, &1, &100);
 
         // No auth mocked – should panic
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
     }
 
     #[test]
@@ -471,10 +3157,10 @@ mod test {
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         assert!(client.has_access(&buyer, &creator, &1));
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         assert!(client.has_access(&buyer, &creator, &1));
     }
 
@@ -495,7 +3181,7 @@ mod test {
 
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
         assert!(!client.has_access(&buyer2, &creator, &1));
@@ -510,7 +3196,7 @@ mod test {
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
         client.set_content_price(&creator2, &1, &100);
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
         assert!(!client.has_access(&buyer, &creator2, &1));
@@ -524,7 +3210,7 @@ mod test {
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
         client.set_content_price(&creator, &2, &100);
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
         assert!(!client.has_access(&buyer, &creator, &2));
@@ -540,9 +3226,9 @@ mod test {
         client.set_content_price(&creator, &2, &150);
         client.set_content_price(&creator, &3, &200);
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
-        client.unlock_content(&buyer, &creator, &2, &NO_EXPIRY);
-        client.unlock_content(&buyer, &creator, &3, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
+        client.unlock_content(&buyer, &creator, &2, &LIFETIME);
+        client.unlock_content(&buyer, &creator, &3, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
         assert!(client.has_access(&buyer, &creator, &2));
@@ -558,8 +3244,8 @@ mod test {
 
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
-        client.unlock_content(&buyer2, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
+        client.unlock_content(&buyer2, &creator, &1, &LIFETIME);
 
         assert!(client.has_access(&buyer, &creator, &1));
         assert!(client.has_access(&buyer2, &creator, &1));
@@ -636,9 +3322,10 @@ mod test {
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
-        client.set_content_price(&creator, &1, &100);
+        set_rental(&client, &creator, 1, 100);
 
-        client.unlock_content(&buyer, &creator, &1, &1100);
+        // 100-ledger rental bought at ledger 1000 expires at ledger 1100.
+        client.unlock_content(&buyer, &creator, &1, &0);
         assert!(
             client.has_access(&buyer, &creator, &1),
             "should have access before expiry"
@@ -672,7 +3359,7 @@ mod test {
         client.set_content_price(&creator, &1, &100);
         client.set_content_price(&creator, &2, &200);
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
 
         env.set_auths(EMPTY_AUTHS);
         let result = client.try_verify_access(&buyer, &creator, &2);
@@ -700,7 +3387,7 @@ mod test {
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         assert!(client.has_access(&buyer, &creator, &1));
 
         env.set_auths(EMPTY_AUTHS);
@@ -728,7 +3415,7 @@ mod test {
 
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &42, &750);
-        client.unlock_content(&buyer, &creator, &42, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &42, &LIFETIME);
 
         let all_events = env.events().all();
         let unlock_event = all_events.iter().find(|e| {
@@ -748,9 +3435,13 @@ mod test {
         let event_creator: Address = event.1.get(2).unwrap().try_into_val(&env).unwrap();
         assert_eq!(event_creator, creator);
 
-        let (event_content_id, event_amount): (u64, i128) = event.2.try_into_val(&env).unwrap();
+        let (event_content_id, list_price, gross, fee, net): (u64, i128, i128, i128, i128) =
+            event.2.try_into_val(&env).unwrap();
         assert_eq!(event_content_id, 42u64);
-        assert_eq!(event_amount, 750i128);
+        assert_eq!(list_price, 750i128);
+        assert_eq!(gross, 750i128);
+        assert_eq!(fee, 0i128);
+        assert_eq!(net, 750i128);
     }
 
     #[test]
@@ -761,7 +3452,7 @@ mod test {
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         let count_after_first = env
             .events()
             .all()
@@ -773,7 +3464,7 @@ mod test {
             })
             .count();
 
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         let count_after_second = env
             .events()
             .all()
@@ -868,18 +3559,18 @@ mod test {
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
-        client.set_content_price(&creator, &1, &100);
+        set_rental(&client, &creator, 1, 100);
 
         // First purchase expires at ledger 1100.
-        client.unlock_content(&buyer, &creator, &1, &1100);
+        client.unlock_content(&buyer, &creator, &1, &0);
         assert!(client.has_access(&buyer, &creator, &1));
 
         // Advance past expiry.
         env.ledger().with_mut(|li| li.sequence_number = 1101);
         assert!(!client.has_access(&buyer, &creator, &1));
 
-        // Re-purchase with a new expiry.
-        client.unlock_content(&buyer, &creator, &1, &2000);
+        // Re-purchase starts a fresh rental from the current ledger.
+        client.unlock_content(&buyer, &creator, &1, &0);
         assert!(
             client.has_access(&buyer, &creator, &1),
             "re-purchase should restore access"
@@ -908,7 +3599,7 @@ mod test {
         );
 
         // After unlock, access should be granted and verify should succeed
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         assert!(client.has_access(&buyer, &creator, &1));
         // verify_access should not panic (we test this by not expecting an error)
         let verify_result = client.try_verify_access(&buyer, &creator, &1);
@@ -938,7 +3629,7 @@ mod test {
         );
 
         // After unlock, both should work
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
         assert!(client.has_access(&buyer, &creator, &1));
         let verify_result = client.try_verify_access(&buyer, &creator, &1);
         assert!(
@@ -976,99 +3667,85 @@ mod test {
         assert_eq!(client.admin(), admin2);
     }
 
-    // ── #1385 – Expiry validation ───────────────────────────────────────────
+    // ── Access option selection ─────────────────────────────────────────────
 
-    /// Past expiry ledger is rejected.
+    /// Rental expiry is computed from the purchase ledger, not supplied by the buyer.
     #[test]
-    fn test_unlock_content_with_past_expiry_rejected() {
+    fn test_unlock_content_rental_expiry_from_option() {
         let (env, contract_id, admin, token_address, buyer, creator) = setup_test();
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
-        client.set_content_price(&creator, &1, &100);
-
-        // Current ledger is 1000, try with expiry = 999 (past)
-        let result = client.try_unlock_content(&buyer, &creator, &1, &999);
-        assert_eq!(
-            result,
-            Err(Ok(SorobanError::from_contract_error(
-                Error::InvalidExpiry as u32,
-            ))),
-            "past expiry must be rejected"
-        );
-    }
-
-    /// Current ledger expiry (equal) is rejected.
-    #[test]
-    fn test_unlock_content_with_equal_expiry_rejected() {
-        let (env, contract_id, admin, token_address, buyer, creator) = setup_test();
-        let client = ContentAccessClient::new(&env, &contract_id);
+        set_rental(&client, &creator, 1, 1);
 
-        client.initialize(&admin, &token_address);
-        client.set_content_price(&creator, &1, &100);
+        // Current ledger is 1000, a 1-ledger rental expires at 1001.
+        client.unlock_content(&buyer, &creator, &1, &0);
+        assert!(client.has_access(&buyer, &creator, &1));
 
-        // Current ledger is 1000, try with expiry = 1000 (equal - immediately expired)
-        let result = client.try_unlock_content(&buyer, &creator, &1, &1000);
-        assert_eq!(
-            result,
-            Err(Ok(SorobanError::from_contract_error(
-                Error::InvalidExpiry as u32,
-            ))),
-            "equal expiry must be rejected"
-        );
+        env.ledger().with_mut(|li| li.sequence_number = 1001);
+        assert!(!client.has_access(&buyer, &creator, &1));
     }
 
-    /// Future expiry is accepted.
+    /// Unscheduled content offers lifetime access at the content price.
     #[test]
-    fn test_unlock_content_with_future_expiry_accepted() {
+    fn test_unlock_content_lifetime_default_never_expires() {
         let (env, contract_id, admin, token_address, buyer, creator) = setup_test();
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
 
-        // Current ledger is 1000, try with expiry = 1001 (future)
-        client.unlock_content(&buyer, &creator, &1, &1001);
+        client.unlock_content(&buyer, &creator, &1, &LIFETIME);
+        env.ledger().with_mut(|li| li.sequence_number = 5_000_000);
         assert!(
             client.has_access(&buyer, &creator, &1),
-            "future expiry must be accepted and grant access"
+            "lifetime option must never expire"
         );
     }
 
-    /// u64::MAX (non-expiring) is accepted.
+    /// An option index that the creator has not enabled is rejected.
     #[test]
-    fn test_unlock_content_with_max_expiry_accepted() {
+    fn test_unlock_content_with_unknown_option_rejected() {
         let (env, contract_id, admin, token_address, buyer, creator) = setup_test();
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
         client.set_content_price(&creator, &1, &100);
 
-        // u64::MAX is well above current ledger (1000)
-        client.unlock_content(&buyer, &creator, &1, &NO_EXPIRY);
-        assert!(
-            client.has_access(&buyer, &creator, &1),
-            "u64::MAX expiry must be accepted"
+        let result = client.try_unlock_content(&buyer, &creator, &1, &1);
+        assert_eq!(
+            result,
+            Err(Ok(SorobanError::from_contract_error(
+                Error::AccessOptionNotFound as u32,
+            ))),
+            "only the lifetime option exists for unscheduled content"
         );
     }
 
-    /// Zero expiry is rejected (zero is always <= current ledger > 0).
+    /// Zero-ledger rentals would be immediately expired and are rejected.
     #[test]
-    fn test_unlock_content_with_zero_expiry_rejected() {
-        let (env, contract_id, admin, token_address, buyer, creator) = setup_test();
+    fn test_set_access_options_with_zero_duration_rejected() {
+        let (env, contract_id, admin, token_address, _, creator) = setup_test();
         let client = ContentAccessClient::new(&env, &contract_id);
 
         client.initialize(&admin, &token_address);
-        client.set_content_price(&creator, &1, &100);
-
-        // Current ledger is 1000, expiry = 0 is immediately expired
-        let result = client.try_unlock_content(&buyer, &creator, &1, &0);
+        let result = client.try_set_access_options(
+            &creator,
+            &1,
+            &soroban_sdk::vec![
+                &env,
+                AccessOption {
+                    duration_ledgers: Some(0),
+                    price: 100,
+                },
+            ],
+        );
         assert_eq!(
             result,
             Err(Ok(SorobanError::from_contract_error(
                 Error::InvalidExpiry as u32,
             ))),
-            "zero expiry must be rejected"
+            "zero duration must be rejected"
         );
     }
 }
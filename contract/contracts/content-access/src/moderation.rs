@@ -0,0 +1,44 @@
+//! Admin moderation state for content (legal takedowns, policy holds).
+//!
+//! Suspended content cannot be bought but existing buyers keep access;
+//! removed content cannot be bought and `has_access` reports no access.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationStatus {
+    Active,
+    Suspended,
+    Removed,
+}
+
+/// Latest moderation action for a content item.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModerationRecord {
+    pub status: ModerationStatus,
+    /// Platform-defined reason code (e.g. copyright claim, policy violation).
+    pub reason: u32,
+    /// Ledger sequence of the action.
+    pub updated_at: u64,
+}
+
+pub fn status(env: &Env, creator: &Address, content_id: u64) -> ModerationStatus {
+    env.storage()
+        .instance()
+        .get::<DataKey, ModerationRecord>(&DataKey::Moderation(creator.clone(), content_id))
+        .map_or(ModerationStatus::Active, |r| r.status)
+}
+
+pub fn is_removed(env: &Env, creator: &Address, content_id: u64) -> bool {
+    status(env, creator, content_id) == ModerationStatus::Removed
+}
+
+/// Reject new purchases of suspended or removed content.
+pub fn require_purchasable(env: &Env, creator: &Address, content_id: u64) {
+    if status(env, creator, content_id) != ModerationStatus::Active {
+        panic_with_error!(env, Error::ContentModerated);
+    }
+}
@@ -0,0 +1,210 @@
+//! Pre-orders: content sold before its release ledger.
+//!
+//! Payments for unreleased content are held by the contract, and each one is
+//! recorded as a `PreOrder` until the creator's proceeds are settled after
+//! release or the release is cancelled and every holder refunded. Both run
+//! in batches of at most `MAX_PREORDER_BATCH`, oldest pre-orders first.
+//!
+//! The escrow entry is the source of truth for what is held, so later
+//! changes to a holder's `Receipt` never affect settlement; holders are kept
+//! from re-buying or reselling until their escrow is settled.
+//!
+//! Entries live in persistent storage with one key per pre-order, so a
+//! popular release never grows the contract instance. They occupy the index
+//! range `[head, tail)`: batches consume from the head, and a single refunded
+//! pre-order is replaced by the newest one.
+
+use crate::DataKey;
+use soroban_sdk::{
+    contracterror, contracttype, panic_with_error, Address, Env, IntoVal, TryFromVal, Val, Vec,
+};
+
+/// Maximum number of pre-orders paid out by one `settle_preorders` call or
+/// refunded by one `cancel_release` call.
+pub const MAX_PREORDER_BATCH: u32 = 50;
+
+/// Once an escrow entry's remaining TTL drops below this many ledgers (about
+/// 30 days), it is refreshed back up to `PREORDER_TTL_EXTEND_TO`.
+const PREORDER_TTL_THRESHOLD: u32 = 518_400;
+/// About 180 days. See [`PREORDER_TTL_THRESHOLD`].
+const PREORDER_TTL_EXTEND_TO: u32 = 3_110_400;
+
+/// Pre-order error codes, continuing the numbering of [`crate::Error`].
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PreOrderError {
+    /// Code 55 – holder's pre-order payment is still escrowed; settle it first.
+    EscrowPending = 55,
+    /// Code 56 – a cancelled release still has pre-orders awaiting refund.
+    RefundsPending = 56,
+}
+
+/// Payment escrowed for one holder's pre-order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreOrder {
+    pub holder: Address,
+    /// Address refunded if the release is cancelled or the purchase refunded.
+    pub payer: Address,
+    pub gross: i128,
+    /// Platform fee paid out of `gross` on settlement.
+    pub fee: i128,
+}
+
+/// Persistent escrow keys, nested under `DataKey::PreOrders`.
+#[contracttype]
+#[derive(Clone)]
+pub enum PreOrderKey {
+    /// Index range of the escrowed pre-orders: (creator, content_id) -> (head, tail)
+    Range(Address, u64),
+    /// Pre-order at an index: (creator, content_id, index) -> PreOrder
+    Entry(Address, u64, u32),
+    /// Index of a holder's pre-order: (creator, content_id, holder) -> u32
+    Position(Address, u64, Address),
+}
+
+fn get<V: TryFromVal<Env, Val>>(env: &Env, key: PreOrderKey) -> Option<V> {
+    let key = DataKey::PreOrders(key);
+    let value = env.storage().persistent().get(&key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PREORDER_TTL_THRESHOLD, PREORDER_TTL_EXTEND_TO);
+    }
+    value
+}
+
+fn set<V: IntoVal<Env, Val>>(env: &Env, key: PreOrderKey, value: &V) {
+    let key = DataKey::PreOrders(key);
+    env.storage().persistent().set(&key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PREORDER_TTL_THRESHOLD, PREORDER_TTL_EXTEND_TO);
+}
+
+fn unset(env: &Env, key: PreOrderKey) {
+    env.storage().persistent().remove(&DataKey::PreOrders(key));
+}
+
+fn position_key(creator: &Address, content_id: u64, holder: &Address) -> PreOrderKey {
+    PreOrderKey::Position(creator.clone(), content_id, holder.clone())
+}
+
+fn entry(env: &Env, creator: &Address, content_id: u64, index: u32) -> Option<PreOrder> {
+    get(env, PreOrderKey::Entry(creator.clone(), content_id, index))
+}
+
+fn range(env: &Env, creator: &Address, content_id: u64) -> (u32, u32) {
+    get(env, PreOrderKey::Range(creator.clone(), content_id)).unwrap_or((0, 0))
+}
+
+fn set_range(env: &Env, creator: &Address, content_id: u64, head: u32, tail: u32) {
+    let key = PreOrderKey::Range(creator.clone(), content_id);
+    if head == tail {
+        unset(env, key);
+    } else {
+        set(env, key, &(head, tail));
+    }
+}
+
+pub fn release_ledger(env: &Env, creator: &Address, content_id: u64) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReleaseLedger(creator.clone(), content_id))
+}
+
+pub fn is_unreleased(env: &Env, creator: &Address, content_id: u64) -> bool {
+    release_ledger(env, creator, content_id)
+        .is_some_and(|release| (env.ledger().sequence() as u64) < release)
+}
+
+/// Number of escrowed pre-orders.
+pub fn count(env: &Env, creator: &Address, content_id: u64) -> u32 {
+    let (head, tail) = range(env, creator, content_id);
+    tail - head
+}
+
+/// Holders whose pre-order payments are still held by the contract, oldest
+/// first.
+pub fn holders(env: &Env, creator: &Address, content_id: u64) -> Vec<Address> {
+    let mut holders = Vec::new(env);
+    let (head, tail) = range(env, creator, content_id);
+    for index in head..tail {
+        if let Some(entry) = entry(env, creator, content_id, index) {
+            holders.push_back(entry.holder);
+        }
+    }
+    holders
+}
+
+pub fn is_pending(env: &Env, creator: &Address, content_id: u64, holder: &Address) -> bool {
+    get::<u32>(env, position_key(creator, content_id, holder)).is_some()
+}
+
+/// Reject a new purchase for `holder` while an earlier one is still escrowed.
+pub fn require_not_pending(env: &Env, creator: &Address, content_id: u64, holder: &Address) {
+    if is_pending(env, creator, content_id, holder) {
+        panic_with_error!(env, PreOrderError::EscrowPending);
+    }
+}
+
+pub fn add(env: &Env, creator: &Address, content_id: u64, entry: PreOrder) {
+    let (head, tail) = range(env, creator, content_id);
+    set(env, position_key(creator, content_id, &entry.holder), &tail);
+    set(
+        env,
+        PreOrderKey::Entry(creator.clone(), content_id, tail),
+        &entry,
+    );
+    set_range(env, creator, content_id, head, tail + 1);
+}
+
+/// Remove and return up to `limit` of the oldest escrow entries.
+pub fn take(env: &Env, creator: &Address, content_id: u64, limit: u32) -> Vec<PreOrder> {
+    let (head, tail) = range(env, creator, content_id);
+    let end = core::cmp::min(head.saturating_add(limit), tail);
+    let mut taken = Vec::new(env);
+    for index in head..end {
+        if let Some(entry) = entry(env, creator, content_id, index) {
+            unset(env, position_key(creator, content_id, &entry.holder));
+            taken.push_back(entry);
+        }
+        unset(env, PreOrderKey::Entry(creator.clone(), content_id, index));
+    }
+    set_range(env, creator, content_id, end, tail);
+    taken
+}
+
+/// Drop `holder`'s escrow entry, returning it if there was one. The newest
+/// entry takes its place.
+pub fn remove_holder(
+    env: &Env,
+    creator: &Address,
+    content_id: u64,
+    holder: &Address,
+) -> Option<PreOrder> {
+    let position = position_key(creator, content_id, holder);
+    let index: u32 = get(env, position.clone())?;
+    let removed = entry(env, creator, content_id, index);
+    unset(env, position);
+
+    let (head, tail) = range(env, creator, content_id);
+    let last = tail - 1;
+    if index != last {
+        if let Some(moved) = entry(env, creator, content_id, last) {
+            set(
+                env,
+                position_key(creator, content_id, &moved.holder),
+                &index,
+            );
+            set(
+                env,
+                PreOrderKey::Entry(creator.clone(), content_id, index),
+                &moved,
+            );
+        }
+    }
+    unset(env, PreOrderKey::Entry(creator.clone(), content_id, last));
+    set_range(env, creator, content_id, head, last);
+    removed
+}
@@ -0,0 +1,189 @@
+//! Admin price rules: floors and caps by content category, creator trust
+//! level, or globally.
+//!
+//! Each bound comes from the most specific rule that sets it: the content's
+//! category, then the creator's trust level, then the global rule (stored as
+//! `MinPrice` / `MaxPrice`). Creators without an assigned level are at trust
+//! level 0, so rules for level 0 apply to unverified creators.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PriceScope {
+    Global,
+    Category(u32),
+    TrustLevel(u32),
+}
+
+/// Price bounds for one scope; `None` defers to the next, less specific rule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceRule {
+    pub min_price: Option<i128>,
+    pub max_price: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriceBound {
+    Floor,
+    Cap,
+}
+
+/// The rule a price breaks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceViolation {
+    pub scope: PriceScope,
+    pub bound: PriceBound,
+    pub limit: i128,
+}
+
+pub fn rule(env: &Env, scope: &PriceScope) -> PriceRule {
+    match scope {
+        PriceScope::Global => PriceRule {
+            min_price: env.storage().instance().get(&DataKey::MinPrice),
+            max_price: env.storage().instance().get(&DataKey::MaxPrice),
+        },
+        _ => env
+            .storage()
+            .instance()
+            .get(&DataKey::PriceRule(scope.clone()))
+            .unwrap_or(PriceRule {
+                min_price: None,
+                max_price: None,
+            }),
+    }
+}
+
+pub fn require_valid_rule(env: &Env, rule: &PriceRule) {
+    let positive = |bound: Option<i128>| !matches!(bound, Some(p) if p <= 0);
+    let ordered = match (rule.min_price, rule.max_price) {
+        (Some(min), Some(max)) => min <= max,
+        _ => true,
+    };
+    if !positive(rule.min_price) || !positive(rule.max_price) || !ordered {
+        panic_with_error!(env, Error::InvalidPriceRule);
+    }
+}
+
+pub fn set_rule(env: &Env, scope: &PriceScope, rule: &PriceRule) {
+    let storage = env.storage().instance();
+    match scope {
+        PriceScope::Global => {
+            for (key, bound) in [
+                (DataKey::MinPrice, rule.min_price),
+                (DataKey::MaxPrice, rule.max_price),
+            ] {
+                match bound {
+                    Some(price) => storage.set(&key, &price),
+                    None => storage.remove(&key),
+                }
+            }
+        }
+        _ if rule.min_price.is_none() && rule.max_price.is_none() => {
+            storage.remove(&DataKey::PriceRule(scope.clone()))
+        }
+        _ => storage.set(&DataKey::PriceRule(scope.clone()), rule),
+    }
+}
+
+pub fn category(env: &Env, creator: &Address, content_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContentCategory(creator.clone(), content_id))
+}
+
+pub fn trust_level(env: &Env, creator: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TrustLevel(creator.clone()))
+        .unwrap_or(0)
+}
+
+/// A price limit together with the scope of the rule that sets it.
+type Bound = Option<(PriceScope, i128)>;
+
+/// Floor and cap applying to `creator`'s content.
+fn bounds(env: &Env, creator: &Address, content_id: Option<u64>) -> (Bound, Bound) {
+    let category = content_id.and_then(|id| category(env, creator, id));
+    let scopes = [
+        category.map(PriceScope::Category),
+        Some(PriceScope::TrustLevel(trust_level(env, creator))),
+        Some(PriceScope::Global),
+    ];
+    let mut floor = None;
+    let mut cap = None;
+    for scope in scopes.into_iter().flatten() {
+        let rule = rule(env, &scope);
+        if floor.is_none() {
+            floor = rule.min_price.map(|limit| (scope.clone(), limit));
+        }
+        if cap.is_none() {
+            cap = rule.max_price.map(|limit| (scope, limit));
+        }
+    }
+    (floor, cap)
+}
+
+/// Lowest price allowed for (creator, content_id), if any rule sets one.
+pub fn floor(env: &Env, creator: &Address, content_id: u64) -> Option<i128> {
+    bounds(env, creator, Some(content_id))
+        .0
+        .map(|(_, limit)| limit)
+}
+
+/// First rule `price` breaks for `creator`'s content. Bundles pass `None`
+/// for `content_id` and are only held to trust-level and global rules.
+pub fn violation(
+    env: &Env,
+    creator: &Address,
+    content_id: Option<u64>,
+    price: i128,
+) -> Option<PriceViolation> {
+    let (floor, cap) = bounds(env, creator, content_id);
+    if let Some((scope, limit)) = floor.filter(|(_, limit)| price < *limit) {
+        return Some(PriceViolation {
+            scope,
+            bound: PriceBound::Floor,
+            limit,
+        });
+    }
+    cap.filter(|(_, limit)| price > *limit)
+        .map(|(scope, limit)| PriceViolation {
+            scope,
+            bound: PriceBound::Cap,
+            limit,
+        })
+}
+
+/// Error naming the rule scope and bound a price breaks.
+fn error(scope: PriceScope, bound: PriceBound) -> Error {
+    match (scope, bound) {
+        (PriceScope::Global, PriceBound::Cap) => Error::PriceExceedsMax,
+        (PriceScope::Global, PriceBound::Floor) => Error::PriceBelowMin,
+        (PriceScope::Category(_), PriceBound::Cap) => Error::PriceExceedsCategoryMax,
+        (PriceScope::Category(_), PriceBound::Floor) => Error::PriceBelowCategoryMin,
+        (PriceScope::TrustLevel(_), PriceBound::Cap) => Error::PriceExceedsTrustMax,
+        (PriceScope::TrustLevel(_), PriceBound::Floor) => Error::PriceBelowTrustMin,
+    }
+}
+
+/// Reject `price` with the error naming the rule it breaks.
+pub fn require_within_rules(env: &Env, creator: &Address, content_id: Option<u64>, price: i128) {
+    if let Some(violation) = violation(env, creator, content_id, price) {
+        panic_with_error!(env, error(violation.scope, violation.bound));
+    }
+}
+
+/// Reject `price` if it is below the floor for (creator, content_id), with
+/// the error naming the rule that sets the floor.
+pub fn require_above_floor(env: &Env, creator: &Address, content_id: u64, price: i128) {
+    if let Some((scope, limit)) = bounds(env, creator, Some(content_id)).0 {
+        if price < limit {
+            panic_with_error!(env, error(scope, PriceBound::Floor));
+        }
+    }
+}
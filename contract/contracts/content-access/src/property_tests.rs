@@ -6,7 +6,7 @@
 mod props {
     extern crate std;
 
-    use crate::{ContentAccess, ContentAccessClient, Error};
+    use crate::{AccessOption, ContentAccess, ContentAccessClient, Error};
     use proptest::prelude::*;
     use soroban_sdk::{
         contract, contractimpl,
@@ -84,12 +84,12 @@ mod props {
         #![proptest_config(ProptestConfig::with_cases(16))]
 
         /// For any set of random unlock operations, the contract's access state
-        /// must remain consistent: has_access and verify_access agree, and expiry
-        /// boundaries are respected.
+        /// must remain consistent: has_access and verify_access agree, and the
+        /// expiry derived from the creator's rental option is respected.
         #[test]
         fn prop_unlock_preserves_access_invariants(
             ops in prop::collection::vec(
-                (0u64..5u64, 0u64..5u64, 1u64..10_000u64),
+                (0u64..5u64, 0u64..5u64, 0u64..10_000u64),
                 1..16usize
             )
         ) {
@@ -99,42 +99,43 @@ mod props {
             let buyers: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
             let creators: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
 
-            // Set prices for all (creator, content_id) combos we might use
-            for creator in &creators {
-                for content_id in 0u64..5u64 {
-                    client.set_content_price(creator, &content_id, &100);
-                }
-            }
-
-            for (buyer_idx, creator_idx, expiry_ledger) in ops {
+            for (buyer_idx, creator_idx, duration) in ops {
                 let buyer = &buyers[buyer_idx as usize % 3];
                 let creator = &creators[creator_idx as usize % 3];
                 let content_id = buyer_idx % 5;
 
-                let current_seq: u64 = env.ledger().sequence() as u64;
-                let unlock_result = client.try_unlock_content(buyer, creator, &content_id, &expiry_ledger);
+                let options = soroban_sdk::vec![
+                    &env,
+                    AccessOption {
+                        duration_ledgers: Some(duration),
+                        price: 100,
+                    },
+                ];
+                let set_result = client.try_set_access_options(creator, &content_id, &options);
 
-                if expiry_ledger <= current_seq {
+                if duration == 0 {
                     prop_assert_eq!(
-                        unlock_result,
+                        set_result,
                         Err(Ok(SorobanError::from_contract_error(
                             Error::InvalidExpiry as u32,
                         ))),
-                        "expiry_ledger <= current_seq must be rejected with InvalidExpiry"
+                        "zero-ledger rentals must be rejected with InvalidExpiry"
                     );
                     prop_assert!(!client.has_access(buyer, creator, &content_id));
                 } else {
-                    prop_assert!(unlock_result.is_ok());
+                    prop_assert!(set_result.is_ok());
+                    let current_seq: u64 = env.ledger().sequence() as u64;
+                    prop_assert!(client.try_unlock_content(buyer, creator, &content_id, &0).is_ok());
                     prop_assert!(client.has_access(buyer, creator, &content_id));
 
-                    let new_seq = current_seq + (expiry_ledger - current_seq) + 1;
+                    let new_seq = current_seq + duration;
                     env.ledger().with_mut(|li| {
                         li.sequence_number = new_seq as u32;
                     });
 
                     prop_assert!(
                         !client.has_access(buyer, creator, &content_id),
-                        "access must expire after expiry ledger is passed"
+                        "access must expire once the rental duration has elapsed"
                     );
                 }
             }
@@ -194,7 +195,7 @@ mod props {
             client.set_content_price(&creator, &content_id, &100);
 
             // Only buyer1 unlocks
-            client.unlock_content(&buyer1, &creator, &content_id, &u64::MAX);
+            client.unlock_content(&buyer1, &creator, &content_id, &0);
 
             prop_assert!(client.has_access(&buyer1, &creator, &content_id));
             prop_assert!(!client.has_access(&buyer2, &creator, &content_id));
@@ -0,0 +1,32 @@
+//! Optional creator-registry gate on pricing and sales.
+//!
+//! Off until the admin sets a registry contract. While on, only creators the
+//! registry knows can price content or have it sold; unregistering a creator
+//! there suspends their sales here. Existing buyers keep access.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contractclient, panic_with_error, Address, Env};
+
+/// Interface content-access expects from the creator-registry contract.
+#[contractclient(name = "CreatorRegistryClient")]
+pub trait CreatorRegistry {
+    /// Creator ID registered for `address`, or `None`.
+    fn get_creator_id(env: Env, address: Address) -> Option<u64>;
+}
+
+pub fn contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::CreatorRegistry)
+}
+
+/// Reject `creator` if a registry is configured and does not list them.
+pub fn require_registered(env: &Env, creator: &Address) {
+    let Some(registry) = contract(env) else {
+        return;
+    };
+    if CreatorRegistryClient::new(env, &registry)
+        .get_creator_id(creator)
+        .is_none()
+    {
+        panic_with_error!(env, Error::CreatorNotRegistered);
+    }
+}
@@ -0,0 +1,54 @@
+//! Opt-in resale of permanent purchases, with a creator royalty on each sale.
+//!
+//! Only the holder's own permanent `Access` record can change hands. Rentals,
+//! pre-orders not yet released or settled and access held through a bundle
+//! stay with the buyer.
+
+use crate::{bundle, preorder, DataKey, Error, Purchase};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Creator royalty in basis points, or `None` if resale is disabled.
+pub fn royalty_bps(env: &Env, creator: &Address, content_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ResaleRoyalty(creator.clone(), content_id))
+}
+
+pub fn listing(env: &Env, seller: &Address, creator: &Address, content_id: u64) -> Option<i128> {
+    env.storage().instance().get(&DataKey::Listing(
+        seller.clone(),
+        creator.clone(),
+        content_id,
+    ))
+}
+
+/// Return `holder`'s purchase of (creator, content_id) if it may be resold.
+pub fn require_resellable(
+    env: &Env,
+    holder: &Address,
+    creator: &Address,
+    content_id: u64,
+) -> Purchase {
+    if royalty_bps(env, creator, content_id).is_none() {
+        panic_with_error!(env, Error::ResaleDisabled);
+    }
+    if preorder::is_unreleased(env, creator, content_id) {
+        panic_with_error!(env, Error::NotYetReleased);
+    }
+    if preorder::is_pending(env, creator, content_id, holder) {
+        panic_with_error!(env, Error::NotResellable);
+    }
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    if bundle::access_expiry(env, holder, creator, content_id).is_some_and(|e| e > current_seq) {
+        panic_with_error!(env, Error::NotResellable);
+    }
+    env.storage()
+        .instance()
+        .get::<DataKey, Purchase>(&DataKey::Access(
+            holder.clone(),
+            creator.clone(),
+            content_id,
+        ))
+        .filter(|p| p.expiry == u64::MAX)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotResellable))
+}
@@ -0,0 +1,90 @@
+//! Scheduled sales: temporary discounts that leave the list price untouched.
+//!
+//! A sale on a single content item takes precedence over a creator-wide
+//! sale while both are running. Sale prices never exceed the list price and
+//! never drop below the applicable admin price floor.
+
+use crate::{pricing, DataKey, Error, MAX_ACCESS_OPTIONS};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SaleDiscount {
+    /// Fixed sale price per access option, by option index. Options past
+    /// the end of the list are not discounted.
+    Price(Vec<i128>),
+    /// Percentage off the list price, in basis points.
+    Bps(u32),
+}
+
+/// A discount running from `start_ledger` (inclusive) to `end_ledger` (exclusive).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sale {
+    pub discount: SaleDiscount,
+    pub start_ledger: u64,
+    pub end_ledger: u64,
+}
+
+fn key(creator: &Address, content_id: Option<u64>) -> DataKey {
+    match content_id {
+        Some(id) => DataKey::Sale(creator.clone(), id),
+        None => DataKey::CreatorSale(creator.clone()),
+    }
+}
+
+/// Sale scheduled for one item, or creator-wide when `content_id` is `None`.
+pub fn get(env: &Env, creator: &Address, content_id: Option<u64>) -> Option<Sale> {
+    env.storage().instance().get(&key(creator, content_id))
+}
+
+pub fn set(env: &Env, creator: &Address, content_id: Option<u64>, sale: &Option<Sale>) {
+    match sale {
+        Some(sale) => env
+            .storage()
+            .instance()
+            .set(&key(creator, content_id), sale),
+        None => env.storage().instance().remove(&key(creator, content_id)),
+    }
+}
+
+pub fn require_valid(env: &Env, sale: &Sale) {
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    let valid_discount = match &sale.discount {
+        SaleDiscount::Price(prices) => {
+            !prices.is_empty()
+                && prices.len() <= MAX_ACCESS_OPTIONS
+                && prices.iter().all(|price| price > 0)
+        }
+        SaleDiscount::Bps(bps) => *bps > 0 && *bps <= 10_000,
+    };
+    if !valid_discount || sale.start_ledger >= sale.end_ledger || sale.end_ledger <= current_seq {
+        panic_with_error!(env, Error::InvalidSale);
+    }
+}
+
+/// The sale currently discounting (creator, content_id), if any.
+pub fn active(env: &Env, creator: &Address, content_id: u64) -> Option<Sale> {
+    let current_seq: u64 = env.ledger().sequence() as u64;
+    let running = |sale: &Sale| sale.start_ledger <= current_seq && current_seq < sale.end_ledger;
+    get(env, creator, Some(content_id))
+        .filter(running)
+        .or_else(|| get(env, creator, None).filter(running))
+}
+
+/// Price charged now for access option `option`, listed at `list_price`.
+pub fn price(env: &Env, creator: &Address, content_id: u64, option: u32, list_price: i128) -> i128 {
+    let Some(sale) = active(env, creator, content_id) else {
+        return list_price;
+    };
+    let discounted = match sale.discount {
+        SaleDiscount::Price(prices) => prices
+            .get(option)
+            .map_or(list_price, |price| core::cmp::min(price, list_price)),
+        SaleDiscount::Bps(bps) => list_price - list_price * bps as i128 / 10_000,
+    };
+    match pricing::floor(env, creator, content_id) {
+        Some(floor) => core::cmp::max(discounted, core::cmp::min(floor, list_price)),
+        None => discounted,
+    }
+}
@@ -0,0 +1,46 @@
+//! Limited editions: an optional cap on the permanent copies of a content item.
+//!
+//! Each permanent purchase of capped content takes the next serial number,
+//! starting at 1. The serials of refunded or cancelled copies are retired
+//! rather than reissued.
+//!
+//! Permanent copies are counted even while the content is uncapped, so a cap
+//! set after sales have begun includes the copies already sold: those stay
+//! unnumbered, and the first numbered copy follows them.
+//!
+//! The cap counts owned copies only. Rentals are time-limited access rather
+//! than copies, so by design they are neither limited nor numbered: capped
+//! content can still be rented after it sells out, and an expired rental
+//! never holds a serial. Creators who want a fixed audience offer only
+//! permanent access options.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+pub fn cap(env: &Env, creator: &Address, content_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SupplyCap(creator.clone(), content_id))
+}
+
+pub fn issued(env: &Env, creator: &Address, content_id: u64) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SerialsIssued(creator.clone(), content_id))
+        .unwrap_or(0)
+}
+
+/// Count a new permanent copy and return its serial, or `None` if uncapped.
+pub fn take_serial(env: &Env, creator: &Address, content_id: u64) -> Option<u32> {
+    let cap = cap(env, creator, content_id);
+    let issued = issued(env, creator, content_id);
+    if cap.is_some_and(|cap| issued >= cap) {
+        panic_with_error!(env, Error::SoldOut);
+    }
+    let serial = issued + 1;
+    env.storage().instance().set(
+        &DataKey::SerialsIssued(creator.clone(), content_id),
+        &serial,
+    );
+    cap.map(|_| serial)
+}
@@ -0,0 +1,218 @@
+use crate::{AccessOption, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, Error as SorobanError, Symbol, TryIntoVal, Vec,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+const HOURS_48: u64 = 2 * 720;
+const DAYS_30: u64 = 30 * 17_280;
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1000;
+        li.min_persistent_entry_ttl = 10_000_000;
+        li.min_temp_entry_ttl = 10_000_000;
+    });
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(env, &contract_id);
+
+    client.initialize(&admin, &token_address);
+    (client, admin, creator)
+}
+
+/// 48-hour rental at 10, 30-day access at 50, lifetime at 200.
+fn schedule(env: &Env) -> Vec<AccessOption> {
+    vec![
+        env,
+        AccessOption {
+            duration_ledgers: Some(HOURS_48),
+            price: 10,
+        },
+        AccessOption {
+            duration_ledgers: Some(DAYS_30),
+            price: 50,
+        },
+        AccessOption {
+            duration_ledgers: None,
+            price: 200,
+        },
+    ]
+}
+
+fn last_unlock_price(env: &Env) -> i128 {
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .rev()
+        .find(|event| {
+            event.1.first().is_some_and(|topic| {
+                topic.try_into_val(env).ok() == Some(Symbol::new(env, "content_unlocked"))
+            })
+        })
+        .expect("content_unlocked event not emitted");
+    let (_, _, price, _, _): (u64, i128, i128, i128, i128) = event.2.try_into_val(env).unwrap();
+    price
+}
+
+#[test]
+fn unscheduled_content_offers_lifetime_at_content_price() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    assert!(client.get_access_options(&creator, &1).is_empty());
+
+    client.set_content_price(&creator, &1, &75);
+    assert_eq!(
+        client.get_access_options(&creator, &1),
+        vec![
+            &env,
+            AccessOption {
+                duration_ledgers: None,
+                price: 75,
+            },
+        ]
+    );
+}
+
+#[test]
+fn each_option_charges_its_price_and_sets_its_expiry() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    client.set_access_options(&creator, &1, &schedule(&env));
+
+    let renter = Address::generate(&env);
+    client.unlock_content(&renter, &creator, &1, &0);
+    assert_eq!(last_unlock_price(&env), 10);
+
+    let monthly = Address::generate(&env);
+    client.unlock_content(&monthly, &creator, &1, &1);
+    assert_eq!(last_unlock_price(&env), 50);
+
+    let owner = Address::generate(&env);
+    client.unlock_content(&owner, &creator, &1, &2);
+    assert_eq!(last_unlock_price(&env), 200);
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number = 1000 + HOURS_48 as u32);
+    assert!(!client.has_access(&renter, &creator, &1));
+    assert!(client.has_access(&monthly, &creator, &1));
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number = 1000 + DAYS_30 as u32);
+    assert!(!client.has_access(&monthly, &creator, &1));
+    assert!(client.has_access(&owner, &creator, &1));
+}
+
+#[test]
+fn schedule_replaces_content_price_fallback() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_content_price(&creator, &1, &75);
+    client.set_access_options(
+        &creator,
+        &1,
+        &vec![
+            &env,
+            AccessOption {
+                duration_ledgers: Some(HOURS_48),
+                price: 10,
+            },
+        ],
+    );
+
+    // Lifetime access at the content price is no longer on offer.
+    client.unlock_content(&buyer, &creator, &1, &0);
+    assert_eq!(last_unlock_price(&env), 10);
+    assert_eq!(
+        client.try_unlock_content(&Address::generate(&env), &creator, &1, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AccessOptionNotFound as u32
+        )))
+    );
+}
+
+#[test]
+fn empty_schedule_restores_fallback() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.set_content_price(&creator, &1, &75);
+    client.set_access_options(&creator, &1, &schedule(&env));
+    client.set_access_options(&creator, &1, &Vec::new(&env));
+
+    assert_eq!(client.get_access_options(&creator, &1).len(), 1);
+    assert_eq!(
+        client
+            .get_access_options(&creator, &1)
+            .get(0)
+            .unwrap()
+            .price,
+        75
+    );
+}
+
+#[test]
+fn schedule_without_content_price_is_purchasable() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_access_options(&creator, &1, &schedule(&env));
+    client.unlock_content(&buyer, &creator, &1, &2);
+    assert!(client.has_access(&buyer, &creator, &1));
+}
+
+#[test]
+fn too_many_options_rejected() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    let mut options = Vec::new(&env);
+    for i in 1..=9u64 {
+        options.push_back(AccessOption {
+            duration_ledgers: Some(i * 100),
+            price: 10,
+        });
+    }
+    assert_eq!(
+        client.try_set_access_options(&creator, &1, &options),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::TooManyAccessOptions as u32
+        )))
+    );
+}
+
+#[test]
+fn option_prices_respect_max_price() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.set_max_price(&100);
+    assert_eq!(
+        client.try_set_access_options(&creator, &1, &schedule(&env)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceExceedsMax as u32
+        )))
+    );
+}
@@ -0,0 +1,215 @@
+use crate::{Attestation, AttestationError, ContentAccess, ContentAccessClient};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, Error as SorobanError,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+/// Attribute code used for "age over 18" in these tests.
+const ADULT: u32 = 18;
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    creator: Address,
+    fan: Address,
+    attestor: SigningKey,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(&env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+    client.set_content_price(&creator, &1, &100);
+    client.set_required_attribute(&creator, &1, &Some(ADULT));
+
+    let attestor = SigningKey::from_bytes(&[9; 32]);
+    client.add_attestor(&public_key(&env, &attestor));
+    Setup {
+        env,
+        client,
+        creator,
+        fan,
+        attestor,
+    }
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn sign(s: &Setup, key: &SigningKey, attestation: &Attestation) -> BytesN<64> {
+    let mut message = [0u8; 512];
+    let bytes = s.client.attestation_message(attestation);
+    let len = bytes.len() as usize;
+    bytes.copy_into_slice(&mut message[..len]);
+    BytesN::from_array(&s.env, &key.sign(&message[..len]).to_bytes())
+}
+
+/// Submit a claim for the fan signed by `key`, returning the contract error.
+fn submit(s: &Setup, key: &SigningKey, expires_at: u64) -> Result<(), SorobanError> {
+    let attestation = Attestation {
+        subject: s.fan.clone(),
+        attribute: ADULT,
+        expires_at,
+    };
+    let signature = sign(s, key, &attestation);
+    match s
+        .client
+        .try_submit_attestation(&attestation, &public_key(&s.env, key), &signature)
+    {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(_)) => panic!("unexpected invoke error"),
+    }
+}
+
+fn set_ledger(env: &Env, sequence_number: u32) {
+    env.ledger()
+        .with_mut(|li| li.sequence_number = sequence_number);
+}
+
+#[test]
+fn restricted_content_needs_a_valid_claim() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_unlock_content(&s.fan, &s.creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            AttestationError::AttestationRequired as u32
+        )))
+    );
+
+    submit(&s, &s.attestor, 2000).unwrap();
+    assert!(s.client.has_attribute(&s.fan, &ADULT));
+    s.client.unlock_content(&s.fan, &s.creator, &1, &0);
+    assert!(s.client.has_access(&s.fan, &s.creator, &1));
+    s.client.verify_access(&s.fan, &s.creator, &1);
+
+    // Unrestricted content is unaffected.
+    s.client.set_content_price(&s.creator, &2, &100);
+    s.client
+        .unlock_content(&Address::generate(&s.env), &s.creator, &2, &0);
+}
+
+#[test]
+fn expired_claim_blocks_access_to_bought_content() {
+    let s = setup();
+    submit(&s, &s.attestor, 1500).unwrap();
+    s.client.unlock_content(&s.fan, &s.creator, &1, &0);
+
+    set_ledger(&s.env, 1500);
+    assert!(!s.client.has_attribute(&s.fan, &ADULT));
+    assert!(!s.client.has_access(&s.fan, &s.creator, &1));
+    assert_eq!(
+        s.client.try_verify_access(&s.fan, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            AttestationError::AttestationRequired as u32
+        )))
+    );
+
+    // A fresh claim restores access to the existing purchase.
+    submit(&s, &s.attestor, 3000).unwrap();
+    assert!(s.client.has_access(&s.fan, &s.creator, &1));
+}
+
+#[test]
+fn revoking_an_attestor_invalidates_its_claims() {
+    let s = setup();
+    submit(&s, &s.attestor, 5000).unwrap();
+    s.client.unlock_content(&s.fan, &s.creator, &1, &0);
+
+    let key = public_key(&s.env, &s.attestor);
+    s.client.remove_attestor(&key);
+    assert!(!s.client.is_attestor(&key));
+    assert!(!s.client.has_access(&s.fan, &s.creator, &1));
+
+    // Re-approval does not revive claims signed before the revocation.
+    set_ledger(&s.env, 1100);
+    s.client.add_attestor(&key);
+    assert!(!s.client.has_attribute(&s.fan, &ADULT));
+    submit(&s, &s.attestor, 5000).unwrap();
+    assert!(s.client.has_access(&s.fan, &s.creator, &1));
+}
+
+#[test]
+fn gifts_and_carts_check_the_holder() {
+    let s = setup();
+    let payer = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_gift_content(&payer, &s.fan, &s.creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            AttestationError::AttestationRequired as u32
+        )))
+    );
+    assert_eq!(
+        s.client.try_unlock_many(
+            &s.fan,
+            &soroban_sdk::vec![
+                &s.env,
+                crate::CartItem {
+                    creator: s.creator.clone(),
+                    content_id: 1,
+                    option: 0,
+                },
+            ],
+        ),
+        Err(Ok(SorobanError::from_contract_error(
+            AttestationError::AttestationRequired as u32
+        )))
+    );
+
+    submit(&s, &s.attestor, 2000).unwrap();
+    s.client.gift_content(&payer, &s.fan, &s.creator, &1, &0);
+    assert!(s.client.has_access(&s.fan, &s.creator, &1));
+}
+
+#[test]
+fn invalid_attestations_rejected() {
+    let s = setup();
+    assert_eq!(
+        submit(&s, &SigningKey::from_bytes(&[3; 32]), 2000),
+        Err(SorobanError::from_contract_error(
+            AttestationError::AttestorNotApproved as u32
+        ))
+    );
+    assert_eq!(
+        submit(&s, &s.attestor, 1000),
+        Err(SorobanError::from_contract_error(
+            AttestationError::AttestationExpired as u32
+        ))
+    );
+
+    // A signature over a different claim traps in verification.
+    let attestation = Attestation {
+        subject: s.fan.clone(),
+        attribute: ADULT,
+        expires_at: 2000,
+    };
+    let signature = BytesN::from_array(&s.env, &s.attestor.sign(b"other").to_bytes());
+    assert!(s
+        .client
+        .try_submit_attestation(&attestation, &public_key(&s.env, &s.attestor), &signature)
+        .is_err());
+    assert_eq!(s.client.get_attestation(&s.fan, &ADULT), None);
+}
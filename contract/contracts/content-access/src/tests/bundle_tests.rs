@@ -0,0 +1,287 @@
+use crate::{events::BundleUnlockedEvent, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, BytesN, Env, Error as SorobanError, Symbol, TryIntoVal, Vec,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1000;
+        li.min_persistent_entry_ttl = 10_000_000;
+        li.min_temp_entry_ttl = 10_000_000;
+    });
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+        buyer,
+    }
+}
+
+fn episodes(env: &Env, n: u64) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for id in 1..=n {
+        ids.push_back(id);
+    }
+    ids
+}
+
+#[test]
+fn unlock_bundle_grants_every_member() {
+    let s = setup();
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 10), &500, &None);
+
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+
+    for id in 1..=10u64 {
+        assert!(s.client.has_access(&s.buyer, &s.creator, &id));
+    }
+    assert!(s.client.has_bundle_access(&s.buyer, &bundle_id));
+    assert_eq!(s.token.balance(&s.creator), 500);
+    assert_eq!(s.token.balance(&s.buyer), 9_500);
+}
+
+#[test]
+fn bundle_ids_are_sequential() {
+    let s = setup();
+    let first = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &None);
+    let second = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 3), &100, &None);
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(s.client.get_bundle(&second).unwrap().content_ids.len(), 3);
+}
+
+#[test]
+fn content_added_later_is_covered_by_bundle() {
+    let s = setup();
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &None);
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &3));
+    s.client.add_to_bundle(&bundle_id, &3);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &3));
+    s.client.verify_access(&s.buyer, &s.creator, &3);
+}
+
+#[test]
+fn partial_ownership_is_credited() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &80);
+    s.client.set_content_price(&s.creator, &2, &70);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    s.client.unlock_content(&s.buyer, &s.creator, &2, &0);
+
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 5), &300, &None);
+    assert_eq!(s.client.get_bundle_price(&s.buyer, &bundle_id), 150);
+    assert_eq!(
+        s.client
+            .get_bundle_price(&Address::generate(&s.env), &bundle_id),
+        300
+    );
+
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(s.token.balance(&s.creator), 80 + 70 + 150);
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1.first().is_some_and(|topic| {
+                topic.try_into_val(&s.env).ok() == Some(Symbol::new(&s.env, "bundle_unlocked"))
+            })
+        })
+        .expect("bundle_unlocked event not emitted");
+    let data: BundleUnlockedEvent = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(
+        data,
+        BundleUnlockedEvent {
+            bundle_id,
+            price: 300,
+            credit: 150,
+            charged: 150,
+            fee: 0,
+        }
+    );
+}
+
+#[test]
+fn expired_rentals_earn_no_credit() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &1,
+        &vec![
+            &s.env,
+            crate::AccessOption {
+                duration_ledgers: Some(10),
+                price: 80,
+            },
+        ],
+    );
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &None);
+    assert_eq!(s.client.get_bundle_price(&s.buyer, &bundle_id), 20);
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1010);
+    assert_eq!(s.client.get_bundle_price(&s.buyer, &bundle_id), 100);
+}
+
+#[test]
+fn credit_never_makes_price_negative() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &500);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &None);
+
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(s.token.balance(&s.creator), 500);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &2));
+}
+
+#[test]
+fn timed_bundle_expires_without_shortening_direct_purchases() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &50);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &Some(100));
+
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    // Repeat purchase while the bundle is valid is a no-op.
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(s.token.balance(&s.creator), 50 + 50);
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1100);
+    assert!(!s.client.has_bundle_access(&s.buyer, &bundle_id));
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &2));
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    assert_eq!(
+        s.client.try_verify_access(&s.buyer, &s.creator, &2),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PurchaseExpired as u32
+        )))
+    );
+}
+
+#[test]
+fn invalid_bundles_rejected() {
+    let s = setup();
+    let invalid = Err(Ok(SorobanError::from_contract_error(
+        Error::InvalidBundle as u32,
+    )));
+
+    assert_eq!(
+        s.client
+            .try_create_bundle(&s.creator, &Vec::new(&s.env), &100, &None),
+        invalid
+    );
+    assert_eq!(
+        s.client
+            .try_create_bundle(&s.creator, &vec![&s.env, 1u64, 1], &100, &None),
+        invalid
+    );
+    assert_eq!(
+        s.client
+            .try_create_bundle(&s.creator, &episodes(&s.env, 51), &100, &None),
+        invalid
+    );
+
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 2), &100, &None);
+    assert_eq!(
+        s.client.try_add_to_bundle(&bundle_id, &2),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidBundle as u32
+        )))
+    );
+    assert_eq!(
+        s.client.try_unlock_bundle(&s.buyer, &99),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::BundleNotFound as u32
+        )))
+    );
+}
+
+#[test]
+fn credited_purchases_cannot_be_refunded() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &80);
+    s.client.set_content_price(&s.creator, &6, &40);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    s.client.unlock_content(&s.buyer, &s.creator, &6, &0);
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 3), &200, &None);
+
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(s.client.get_receipt(&s.buyer, &s.creator, &1), None);
+    assert_eq!(
+        s.client
+            .try_refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &false),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotRefundable as u32
+        )))
+    );
+
+    // Purchases outside the bundle keep their receipts.
+    s.client
+        .refund_purchase(&s.creator, &s.buyer, &s.creator, &6, &false);
+}
+
+#[test]
+fn deactivated_members_block_bundle_purchase() {
+    let s = setup();
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &episodes(&s.env, 3), &200, &None);
+    s.client
+        .register_content(&s.creator, &2, &50, &BytesN::from_array(&s.env, &[2; 32]));
+    s.client.deactivate_content(&s.creator, &2);
+
+    assert_eq!(
+        s.client.try_unlock_bundle(&s.buyer, &bundle_id),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentInactive as u32
+        )))
+    );
+}
@@ -0,0 +1,146 @@
+use crate::{CartItem, ContentAccess, ContentAccessClient, Error, ModerationStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, Symbol, TryIntoVal, Vec,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    for id in 1..=3u64 {
+        client.set_content_price(&alice, &id, &(100 * id as i128));
+        client.set_content_price(&bob, &id, &(1_000 * id as i128));
+    }
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        alice,
+        bob,
+        buyer,
+    }
+}
+
+fn item(creator: &Address, content_id: u64) -> CartItem {
+    CartItem {
+        creator: creator.clone(),
+        content_id,
+        option: 0,
+    }
+}
+
+#[test]
+fn cart_unlocks_every_item_and_pays_each_creator() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    let items = vec![
+        &s.env,
+        item(&s.alice, 1),
+        item(&s.bob, 1),
+        item(&s.alice, 2),
+        item(&s.alice, 3),
+    ];
+
+    s.client.unlock_many(&s.buyer, &items);
+
+    for it in items.iter() {
+        assert!(s.client.has_access(&s.buyer, &it.creator, &it.content_id));
+    }
+    // alice: 600 gross, bob: 1_000 gross, 10% fee each.
+    assert_eq!(s.token.balance(&s.alice), 540);
+    assert_eq!(s.token.balance(&s.bob), 900);
+    assert_eq!(s.token.balance(&s.admin), 160);
+    assert_eq!(s.token.balance(&s.buyer), 10_000 - 1_600);
+
+    let unlocked = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| {
+            event.1.first().is_some_and(|topic| {
+                topic.try_into_val(&s.env).ok() == Some(Symbol::new(&s.env, "content_unlocked"))
+            })
+        })
+        .count();
+    assert_eq!(unlocked, 4);
+}
+
+#[test]
+fn failing_item_aborts_whole_cart() {
+    let s = setup();
+    s.client
+        .moderate_content(&s.bob, &2, &ModerationStatus::Suspended, &0);
+    let items = vec![&s.env, item(&s.alice, 1), item(&s.bob, 2)];
+
+    assert_eq!(
+        s.client.try_unlock_many(&s.buyer, &items),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+    assert!(!s.client.has_access(&s.buyer, &s.alice, &1));
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+}
+
+#[test]
+fn already_owned_items_are_skipped() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.alice, &1, &0);
+    s.client.unlock_many(
+        &s.buyer,
+        &vec![&s.env, item(&s.alice, 1), item(&s.alice, 2)],
+    );
+    assert_eq!(s.token.balance(&s.alice), 100 + 200);
+}
+
+#[test]
+fn invalid_carts_rejected() {
+    let s = setup();
+    let invalid = Err(Ok(SorobanError::from_contract_error(
+        Error::InvalidCart as u32,
+    )));
+
+    assert_eq!(
+        s.client.try_unlock_many(&s.buyer, &Vec::new(&s.env)),
+        invalid
+    );
+    assert_eq!(
+        s.client.try_unlock_many(
+            &s.buyer,
+            &vec![&s.env, item(&s.alice, 1), item(&s.alice, 1)]
+        ),
+        invalid
+    );
+
+    let mut oversized = Vec::new(&s.env);
+    for id in 0..21u64 {
+        oversized.push_back(item(&s.alice, id));
+    }
+    assert_eq!(s.client.try_unlock_many(&s.buyer, &oversized), invalid);
+}
@@ -0,0 +1,236 @@
+use crate::{
+    events::ContentStatusEvent, AccessOption, ContentAccess, ContentAccessClient, ContentInfo,
+    Error,
+};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, Error as SorobanError, Symbol, TryIntoVal,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(env, &contract_id);
+
+    client.initialize(&admin, &token_address);
+    (client, admin, creator)
+}
+
+fn metadata(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn register_content_stores_active_entry_and_legacy_price() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 7));
+
+    assert_eq!(
+        client.get_content(&creator, &1),
+        Some(ContentInfo {
+            price: 500,
+            is_active: true,
+            metadata_hash: metadata(&env, 7),
+        })
+    );
+    assert_eq!(client.get_content_price(&creator, &1), Some(500));
+}
+
+#[test]
+fn register_content_twice_fails() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 1));
+    let result = client.try_register_content(&creator, &1, &600, &metadata(&env, 2));
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentAlreadyRegistered as u32
+        )))
+    );
+}
+
+#[test]
+fn register_content_respects_max_price() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.set_max_price(&1_000);
+    let result = client.try_register_content(&creator, &1, &1_001, &metadata(&env, 1));
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceExceedsMax as u32
+        )))
+    );
+    assert_eq!(client.get_content(&creator, &1), None);
+}
+
+#[test]
+fn set_content_price_updates_catalog_entry() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 1));
+    client.set_content_price(&creator, &1, &750);
+
+    assert_eq!(client.get_content(&creator, &1).unwrap().price, 750);
+    assert_eq!(client.get_content_price(&creator, &1), Some(750));
+}
+
+#[test]
+fn access_options_update_catalog_entry() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 1));
+    client.set_access_options(
+        &creator,
+        &1,
+        &soroban_sdk::vec![
+            &env,
+            AccessOption {
+                duration_ledgers: Some(100),
+                price: 40,
+            },
+            AccessOption {
+                duration_ledgers: None,
+                price: 900,
+            },
+        ],
+    );
+    assert_eq!(client.get_content(&creator, &1).unwrap().price, 40);
+
+    // The content price is unused while a schedule is set.
+    client.set_content_price(&creator, &1, &750);
+    assert_eq!(client.get_content(&creator, &1).unwrap().price, 40);
+
+    client.set_access_options(&creator, &1, &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_content(&creator, &1).unwrap().price, 750);
+}
+
+#[test]
+fn deactivated_content_cannot_be_unlocked() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 1));
+    client.deactivate_content(&creator, &1);
+
+    let result = client.try_unlock_content(&buyer, &creator, &1, &0);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentInactive as u32
+        )))
+    );
+
+    client.activate_content(&creator, &1);
+    client.unlock_content(&buyer, &creator, &1, &0);
+    assert!(client.has_access(&buyer, &creator, &1));
+}
+
+#[test]
+fn existing_buyers_keep_access_after_deactivation() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.register_content(&creator, &1, &500, &metadata(&env, 1));
+    client.unlock_content(&buyer, &creator, &1, &0);
+    client.deactivate_content(&creator, &1);
+
+    assert!(client.has_access(&buyer, &creator, &1));
+    client.verify_access(&buyer, &creator, &1);
+    // Repeat unlock by an existing buyer stays an idempotent no-op.
+    client.unlock_content(&buyer, &creator, &1, &0);
+}
+
+#[test]
+fn status_change_requires_registered_content() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.set_content_price(&creator, &1, &500);
+    let result = client.try_deactivate_content(&creator, &1);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentNotFound as u32
+        )))
+    );
+}
+
+#[test]
+fn deactivate_emits_status_event() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+
+    client.register_content(&creator, &9, &500, &metadata(&env, 1));
+    client.deactivate_content(&creator, &9);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1.first().is_some_and(|topic| {
+                topic.try_into_val(&env).ok() == Some(Symbol::new(&env, "content_deactivated"))
+            })
+        })
+        .expect("content_deactivated event not emitted");
+    let data: ContentStatusEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(
+        data,
+        ContentStatusEvent {
+            creator,
+            content_id: 9,
+        }
+    );
+}
+
+#[test]
+fn list_content_paginates_in_registration_order() {
+    let env = Env::default();
+    let (client, _, creator) = setup(&env);
+    let other = Address::generate(&env);
+
+    for content_id in [30u64, 10, 20] {
+        client.register_content(&creator, &content_id, &100, &metadata(&env, 1));
+    }
+    client.register_content(&other, &99, &100, &metadata(&env, 1));
+
+    let (page, next_cursor) = client.list_content(&creator, &0, &2);
+    assert_eq!(page, soroban_sdk::vec![&env, 30u64, 10]);
+    assert_eq!(next_cursor, 2);
+
+    let (page, next_cursor) = client.list_content(&creator, &next_cursor, &2);
+    assert_eq!(page, soroban_sdk::vec![&env, 20u64]);
+    assert_eq!(next_cursor, 0);
+
+    let (page, next_cursor) = client.list_content(&Address::generate(&env), &0, &10);
+    assert!(page.is_empty());
+    assert_eq!(next_cursor, 0);
+}
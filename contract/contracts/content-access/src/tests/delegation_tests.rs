@@ -0,0 +1,228 @@
+use crate::{AccessOption, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, Error as SorobanError,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+/// Ledgers between delegation changes, mirrored from `delegation.rs`.
+const COOLDOWN: u32 = 17_280;
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1000;
+        li.min_persistent_entry_ttl = 10_000_000;
+        li.min_temp_entry_ttl = 10_000_000;
+    });
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let owner = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+    client.set_content_price(&creator, &1, &100);
+    client.set_share_limit(&creator, &1, &2);
+    client.unlock_content(&owner, &creator, &1, &0);
+    (client, creator, owner)
+}
+
+fn advance(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn delegate_gets_access_until_revoked() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+    let member = Address::generate(&env);
+
+    client.delegate_access(&owner, &creator, &1, &member);
+    assert!(client.has_access(&member, &creator, &1));
+    client.verify_access(&member, &creator, &1);
+    assert_eq!(
+        client.get_delegates(&owner, &creator, &1),
+        vec![&env, member.clone()]
+    );
+
+    advance(&env, COOLDOWN);
+    client.revoke_delegation(&owner, &creator, &1, &member);
+    assert!(!client.has_access(&member, &creator, &1));
+}
+
+#[test]
+fn lapsed_rental_frees_its_delegates() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+    let member = Address::generate(&env);
+    client.set_access_options(
+        &creator,
+        &2,
+        &vec![
+            &env,
+            AccessOption {
+                duration_ledgers: Some(100),
+                price: 40,
+            },
+        ],
+    );
+    client.set_share_limit(&creator, &2, &2);
+    client.unlock_content(&owner, &creator, &2, &0);
+    client.delegate_access(&owner, &creator, &2, &member);
+
+    let other = Address::generate(&env);
+    client.unlock_content(&other, &creator, &2, &0);
+    assert_eq!(
+        client.try_delegate_access(&other, &creator, &2, &member),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyDelegated as u32
+        )))
+    );
+
+    // Both rentals lapse; only the new owner renews.
+    advance(&env, 100);
+    client.unlock_content(&other, &creator, &2, &0);
+    client.delegate_access(&other, &creator, &2, &member);
+    assert!(client.has_access(&member, &creator, &2));
+    assert_eq!(client.get_delegates(&owner, &creator, &2), vec![&env]);
+}
+
+#[test]
+fn sharing_is_disabled_by_default() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+    client.set_content_price(&creator, &2, &100);
+    client.unlock_content(&owner, &creator, &2, &0);
+
+    assert_eq!(client.get_share_limit(&creator, &2), 0);
+    assert_eq!(
+        client.try_delegate_access(&owner, &creator, &2, &Address::generate(&env)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::DelegationLimitReached as u32
+        )))
+    );
+}
+
+#[test]
+fn limit_and_cooldown_are_enforced() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+
+    client.delegate_access(&owner, &creator, &1, &Address::generate(&env));
+    assert_eq!(
+        client.try_delegate_access(&owner, &creator, &1, &Address::generate(&env)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::DelegationCooldown as u32
+        )))
+    );
+
+    advance(&env, COOLDOWN);
+    client.delegate_access(&owner, &creator, &1, &Address::generate(&env));
+    advance(&env, COOLDOWN);
+    assert_eq!(
+        client.try_delegate_access(&owner, &creator, &1, &Address::generate(&env)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::DelegationLimitReached as u32
+        )))
+    );
+}
+
+#[test]
+fn delegation_never_outlives_the_purchase() {
+    let env = Env::default();
+    let (client, creator, _owner) = setup(&env);
+    client.set_access_options(
+        &creator,
+        &3,
+        &vec![
+            &env,
+            crate::AccessOption {
+                duration_ledgers: Some(100),
+                price: 10,
+            },
+        ],
+    );
+    client.set_share_limit(&creator, &3, &1);
+    let renter = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.unlock_content(&renter, &creator, &3, &0);
+    client.delegate_access(&renter, &creator, &3, &member);
+
+    advance(&env, 100);
+    assert!(!client.has_access(&member, &creator, &3));
+    assert_eq!(
+        client.try_verify_access(&member, &creator, &3),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PurchaseExpired as u32
+        )))
+    );
+}
+
+#[test]
+fn refund_ends_delegated_access() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+    let member = Address::generate(&env);
+    client.delegate_access(&owner, &creator, &1, &member);
+
+    client.refund_purchase(&creator, &owner, &creator, &1, &false);
+    assert!(!client.has_access(&member, &creator, &1));
+    assert_eq!(client.get_delegates(&owner, &creator, &1), vec![&env]);
+
+    // The former delegate is free to share someone else's purchase.
+    let other = Address::generate(&env);
+    client.unlock_content(&other, &creator, &1, &0);
+    client.delegate_access(&other, &creator, &1, &member);
+    assert!(client.has_access(&member, &creator, &1));
+}
+
+#[test]
+fn invalid_delegations_rejected() {
+    let env = Env::default();
+    let (client, creator, owner) = setup(&env);
+    let already = Err(Ok(SorobanError::from_contract_error(
+        Error::AlreadyDelegated as u32,
+    )));
+
+    assert_eq!(
+        client.try_delegate_access(&owner, &creator, &1, &owner),
+        already
+    );
+
+    // A delegate can only share one owner's purchase at a time.
+    let member = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    client.unlock_content(&other_owner, &creator, &1, &0);
+    client.delegate_access(&owner, &creator, &1, &member);
+    assert_eq!(
+        client.try_delegate_access(&other_owner, &creator, &1, &member),
+        already
+    );
+
+    // Only buyers can delegate.
+    assert_eq!(
+        client.try_delegate_access(&member, &creator, &1, &Address::generate(&env)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotBuyer as u32
+        )))
+    );
+    assert_eq!(
+        client.try_set_share_limit(&creator, &1, &11),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::DelegationLimitReached as u32
+        )))
+    );
+}
@@ -0,0 +1,159 @@
+use crate::{ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, String, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        creator,
+        buyer,
+    }
+}
+
+#[test]
+fn defaults_to_no_fee_paid_to_admin() {
+    let s = setup();
+    assert_eq!(s.client.fee_bps(), 0);
+    assert_eq!(s.client.fee_recipient(), s.admin);
+
+    s.client.set_content_price(&s.creator, &1, &1_000);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&s.creator), 1_000);
+}
+
+#[test]
+fn sale_is_split_between_platform_and_creator() {
+    let s = setup();
+    let treasury = Address::generate(&s.env);
+    s.client.set_fee_bps(&500);
+    s.client.set_fee_recipient(&treasury);
+
+    s.client.set_content_price(&s.creator, &1, &1_000);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    assert_eq!(s.token.balance(&treasury), 50);
+    assert_eq!(s.token.balance(&s.creator), 950);
+    assert_eq!(s.token.balance(&s.buyer), 9_000);
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (
+                    Symbol::new(&s.env, "content_unlocked"),
+                    s.buyer.clone(),
+                    s.creator.clone(),
+                )
+                    .into_val(&s.env)
+        })
+        .expect("content_unlocked event not emitted");
+    let data: (u64, i128, i128, i128, i128) = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(data, (1, 1_000, 1_000, 50, 950));
+}
+
+#[test]
+fn bundle_sales_pay_the_fee() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &vec![&s.env, 1u64, 2], &400, &None);
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+
+    assert_eq!(s.token.balance(&s.admin), 40);
+    assert_eq!(s.token.balance(&s.creator), 360);
+}
+
+#[test]
+fn full_fee_sends_everything_to_platform() {
+    let s = setup();
+    s.client.set_fee_bps(&10_000);
+
+    s.client.set_content_price(&s.creator, &1, &300);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&s.admin), 300);
+    assert_eq!(s.token.balance(&s.creator), 0);
+}
+
+#[test]
+fn fee_bps_above_100_percent_rejected() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_set_fee_bps(&10_001),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidFeeBps as u32
+        )))
+    );
+    assert_eq!(s.client.fee_bps(), 0);
+}
+
+#[test]
+fn null_fee_recipient_rejected() {
+    let s = setup();
+    let null = Address::from_string(&String::from_str(
+        &s.env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    assert_eq!(
+        s.client.try_set_fee_recipient(&null),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidFeeRecipient as u32
+        )))
+    );
+}
+
+#[test]
+fn fee_setters_emit_events() {
+    let s = setup();
+    let treasury = Address::generate(&s.env);
+    s.client.set_fee_bps(&250);
+    s.client.set_fee_recipient(&treasury);
+
+    let events = s.env.events().all();
+    assert!(events.iter().any(|event| {
+        event.1 == (Symbol::new(&s.env, "fee_updated"),).into_val(&s.env)
+            && event.2.try_into_val(&s.env).ok() == Some((0u32, 250u32))
+    }));
+    assert!(events.iter().any(|event| {
+        event.1
+            == (
+                Symbol::new(&s.env, "fee_recipient_updated"),
+                s.admin.clone(),
+                treasury.clone(),
+            )
+                .into_val(&s.env)
+    }));
+}
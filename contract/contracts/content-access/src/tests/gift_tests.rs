@@ -0,0 +1,132 @@
+use crate::{events::ContentGiftedEvent, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+    payer: Address,
+    recipient: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&payer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_content_price(&creator, &1, &300);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+        payer,
+        recipient,
+    }
+}
+
+#[test]
+fn gift_grants_recipient_access_paid_by_payer() {
+    let s = setup();
+    s.client
+        .gift_content(&s.payer, &s.recipient, &s.creator, &1, &0);
+
+    assert!(s.client.has_access(&s.recipient, &s.creator, &1));
+    assert!(!s.client.has_access(&s.payer, &s.creator, &1));
+    assert_eq!(s.token.balance(&s.payer), 9_700);
+    assert_eq!(s.token.balance(&s.creator), 300);
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (
+                    Symbol::new(&s.env, "content_gifted"),
+                    s.payer.clone(),
+                    s.recipient.clone(),
+                )
+                    .into_val(&s.env)
+        })
+        .expect("content_gifted event not emitted");
+    let data: ContentGiftedEvent = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(
+        data,
+        ContentGiftedEvent {
+            creator: s.creator.clone(),
+            content_id: 1,
+            price: 300,
+            fee: 0,
+        }
+    );
+}
+
+#[test]
+fn gift_to_recipient_with_access_rejected() {
+    let s = setup();
+    s.client
+        .gift_content(&s.payer, &s.recipient, &s.creator, &1, &0);
+    assert_eq!(
+        s.client
+            .try_gift_content(&s.payer, &s.recipient, &s.creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyHasAccess as u32
+        )))
+    );
+    assert_eq!(s.token.balance(&s.payer), 9_700);
+}
+
+#[test]
+fn gift_after_rental_expires_is_allowed() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &1,
+        &vec![
+            &s.env,
+            crate::AccessOption {
+                duration_ledgers: Some(10),
+                price: 50,
+            },
+        ],
+    );
+    s.client
+        .gift_content(&s.payer, &s.recipient, &s.creator, &1, &0);
+    s.env.ledger().with_mut(|li| li.sequence_number = 1010);
+    assert!(!s.client.has_access(&s.recipient, &s.creator, &1));
+
+    s.client
+        .gift_content(&s.payer, &s.recipient, &s.creator, &1, &0);
+    assert!(s.client.has_access(&s.recipient, &s.creator, &1));
+}
+
+#[test]
+fn refunded_gift_returns_funds_to_payer() {
+    let s = setup();
+    s.client
+        .gift_content(&s.payer, &s.recipient, &s.creator, &1, &0);
+    s.client
+        .refund_purchase(&s.creator, &s.recipient, &s.creator, &1, &false);
+
+    assert_eq!(s.token.balance(&s.payer), 10_000);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert!(!s.client.has_access(&s.recipient, &s.creator, &1));
+}
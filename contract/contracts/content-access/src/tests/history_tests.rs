@@ -0,0 +1,247 @@
+use crate::history::HistoryKey;
+use crate::{AccessOption, ContentAccess, ContentAccessClient, DataKey, PurchaseRecord};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    vec, Address, Env,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+    for content_id in 1..=3 {
+        client.set_content_price(&creator, &content_id, &(content_id as i128 * 100));
+    }
+    (client, creator)
+}
+
+fn record(
+    buyer: &Address,
+    creator: &Address,
+    content_id: u64,
+    expiry: u64,
+    price: i128,
+) -> PurchaseRecord {
+    PurchaseRecord {
+        buyer: buyer.clone(),
+        creator: creator.clone(),
+        content_id,
+        expiry,
+        price,
+    }
+}
+
+#[test]
+fn purchases_are_listed_per_buyer_and_per_content() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    let other = Address::generate(&env);
+    for content_id in 1..=3 {
+        client.unlock_content(&buyer, &creator, &content_id, &0);
+    }
+    client.unlock_content(&other, &creator, &2, &0);
+
+    let (page, next) = client.list_purchases_by_buyer(&buyer, &0, &2);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            record(&buyer, &creator, 1, u64::MAX, 100),
+            record(&buyer, &creator, 2, u64::MAX, 200),
+        ]
+    );
+    assert_eq!(next, 2);
+    let (page, next) = client.list_purchases_by_buyer(&buyer, &next, &2);
+    assert_eq!(page, vec![&env, record(&buyer, &creator, 3, u64::MAX, 300)]);
+    assert_eq!(next, 0);
+
+    let (page, next) = client.list_buyers_by_content(&creator, &2, &0, &10);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            record(&buyer, &creator, 2, u64::MAX, 200),
+            record(&other, &creator, 2, u64::MAX, 200),
+        ]
+    );
+    assert_eq!(next, 0);
+    assert_eq!(
+        client.list_purchases_by_buyer(&Address::generate(&env), &0, &10),
+        (vec![&env], 0)
+    );
+}
+
+#[test]
+fn gifts_are_listed_under_the_recipient() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let payer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.gift_content(&payer, &recipient, &creator, &1, &0);
+
+    assert_eq!(
+        client.list_purchases_by_buyer(&payer, &0, &10),
+        (vec![&env], 0)
+    );
+    assert_eq!(
+        client.list_purchases_by_buyer(&recipient, &0, &10),
+        (
+            vec![&env, record(&recipient, &creator, 1, u64::MAX, 100)],
+            0
+        )
+    );
+}
+
+#[test]
+fn expired_rentals_stay_listed_and_renewals_update_in_place() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    client.set_access_options(
+        &creator,
+        &4,
+        &vec![
+            &env,
+            AccessOption {
+                duration_ledgers: Some(100),
+                price: 40,
+            },
+        ],
+    );
+    client.unlock_content(&buyer, &creator, &4, &0);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1200);
+    assert_eq!(
+        client.list_buyers_by_content(&creator, &4, &0, &10),
+        (vec![&env, record(&buyer, &creator, 4, 1100, 40)], 0)
+    );
+
+    client.unlock_content(&buyer, &creator, &4, &0);
+    assert_eq!(
+        client.list_purchases_by_buyer(&buyer, &0, &10),
+        (vec![&env, record(&buyer, &creator, 4, 1300, 40)], 0)
+    );
+}
+
+#[test]
+fn refunds_and_resales_update_the_indexes() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    let refunded = Address::generate(&env);
+    let reseller = Address::generate(&env);
+    client.unlock_content(&refunded, &creator, &1, &0);
+    client.unlock_content(&reseller, &creator, &1, &0);
+
+    client.refund_purchase(&creator, &refunded, &creator, &1, &false);
+    assert_eq!(
+        client.list_purchases_by_buyer(&refunded, &0, &10),
+        (vec![&env], 0)
+    );
+
+    client.set_resale_royalty(&creator, &1, &Some(1_000));
+    client.list_access(&reseller, &creator, &1, &150);
+    client.buy_resale(&buyer, &reseller, &creator, &1);
+    assert_eq!(
+        client.list_purchases_by_buyer(&reseller, &0, &10),
+        (vec![&env], 0)
+    );
+    assert_eq!(
+        client.list_buyers_by_content(&creator, &1, &0, &10),
+        (vec![&env, record(&buyer, &creator, 1, u64::MAX, 150)], 0)
+    );
+}
+
+#[test]
+fn removals_keep_the_lists_dense() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    let others = [Address::generate(&env), Address::generate(&env)];
+    for content_id in 1..=3 {
+        client.unlock_content(&buyer, &creator, &content_id, &0);
+    }
+    for other in others.iter() {
+        client.unlock_content(other, &creator, &1, &0);
+    }
+
+    client.refund_purchase(&creator, &buyer, &creator, &1, &false);
+    assert_eq!(
+        client.list_purchases_by_buyer(&buyer, &0, &10),
+        (
+            vec![
+                &env,
+                record(&buyer, &creator, 3, u64::MAX, 300),
+                record(&buyer, &creator, 2, u64::MAX, 200),
+            ],
+            0
+        )
+    );
+    assert_eq!(
+        client.list_buyers_by_content(&creator, &1, &0, &10),
+        (
+            vec![
+                &env,
+                record(&others[1], &creator, 1, u64::MAX, 100),
+                record(&others[0], &creator, 1, u64::MAX, 100),
+            ],
+            0
+        )
+    );
+}
+
+#[test]
+fn content_added_to_a_bundle_later_is_not_listed() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    let bundle_id = client.create_bundle(&creator, &vec![&env, 1], &100, &None);
+    client.unlock_bundle(&buyer, &bundle_id);
+    client.add_to_bundle(&bundle_id, &2);
+
+    assert!(client.has_access(&buyer, &creator, &2));
+    assert_eq!(
+        client.list_purchases_by_buyer(&buyer, &0, &10),
+        (vec![&env, record(&buyer, &creator, 1, u64::MAX, 0)], 0)
+    );
+}
+
+#[test]
+fn reading_history_keeps_entries_alive() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    client.unlock_content(&buyer, &creator, &1, &0);
+    env.as_contract(&client.address, || {
+        env.storage().instance().extend_ttl(4_000_000, 4_000_000);
+    });
+
+    env.ledger().with_mut(|li| li.sequence_number += 3_000_000);
+    assert_eq!(
+        client.list_purchases_by_buyer(&buyer, &0, &10),
+        (vec![&env, record(&buyer, &creator, 1, u64::MAX, 100)], 0)
+    );
+    env.as_contract(&client.address, || {
+        let key = DataKey::History(HistoryKey::BuyerItem(buyer.clone(), 0));
+        assert_eq!(env.storage().persistent().get_ttl(&key), 3_110_400);
+    });
+}
@@ -324,7 +324,7 @@ fn set_paused_true_pauses_contract() {
     client.set_content_price(&creator, &1, &100);
 
     client.set_paused(&true);
-    let result = client.try_unlock_content(&buyer, &creator, &1, &u64::MAX);
+    let result = client.try_unlock_content(&buyer, &creator, &1, &0);
     assert!(
         result.is_err(),
         "unlock_content must fail when contract is paused"
@@ -343,11 +343,11 @@ fn set_paused_false_unpauses_contract() {
     client.set_content_price(&creator, &1, &100);
 
     client.set_paused(&true);
-    let result = client.try_unlock_content(&buyer, &creator, &1, &u64::MAX);
+    let result = client.try_unlock_content(&buyer, &creator, &1, &0);
     assert!(result.is_err(), "should fail when paused");
 
     client.set_paused(&false);
-    let result = client.try_unlock_content(&buyer, &creator, &1, &u64::MAX);
+    let result = client.try_unlock_content(&buyer, &creator, &1, &0);
     assert!(result.is_ok(), "should succeed after unpause");
 }
 
@@ -0,0 +1,129 @@
+use crate::{
+    events::ContentModeratedEvent, ContentAccess, ContentAccessClient, Error, ModerationStatus,
+};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, Error as SorobanError, Symbol, TryIntoVal,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let buyer = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+    client.set_content_price(&creator, &1, &100);
+    (client, admin, creator, buyer)
+}
+
+#[test]
+fn suspended_content_blocks_sales_but_keeps_access() {
+    let env = Env::default();
+    let (client, _admin, creator, buyer) = setup(&env);
+    client.unlock_content(&buyer, &creator, &1, &0);
+
+    client.moderate_content(&creator, &1, &ModerationStatus::Suspended, &7);
+
+    assert!(client.has_access(&buyer, &creator, &1));
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_unlock_content(&other, &creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+    assert_eq!(
+        client.try_gift_content(&buyer, &other, &creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+}
+
+#[test]
+fn removed_content_revokes_access() {
+    let env = Env::default();
+    let (client, _admin, creator, buyer) = setup(&env);
+    client.unlock_content(&buyer, &creator, &1, &0);
+
+    client.moderate_content(&creator, &1, &ModerationStatus::Removed, &1);
+    assert!(!client.has_access(&buyer, &creator, &1));
+    assert_eq!(
+        client.try_verify_access(&buyer, &creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+
+    // Reinstating restores access for existing buyers.
+    client.moderate_content(&creator, &1, &ModerationStatus::Active, &0);
+    assert!(client.has_access(&buyer, &creator, &1));
+}
+
+#[test]
+fn bundle_with_moderated_member_cannot_be_bought() {
+    let env = Env::default();
+    let (client, _admin, creator, buyer) = setup(&env);
+    let bundle_id = client.create_bundle(&creator, &vec![&env, 1u64, 2], &150, &None);
+
+    client.moderate_content(&creator, &2, &ModerationStatus::Suspended, &3);
+    assert_eq!(
+        client.try_unlock_bundle(&buyer, &bundle_id),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+}
+
+#[test]
+fn moderation_is_recorded_and_emitted() {
+    let env = Env::default();
+    let (client, admin, creator, _buyer) = setup(&env);
+    assert_eq!(client.get_moderation(&creator, &1), None);
+
+    client.moderate_content(&creator, &1, &ModerationStatus::Removed, &42);
+
+    let record = client.get_moderation(&creator, &1).unwrap();
+    assert_eq!(record.status, ModerationStatus::Removed);
+    assert_eq!(record.reason, 42);
+    assert_eq!(record.updated_at, 1000);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1.first().is_some_and(|topic| {
+                topic.try_into_val(&env).ok() == Some(Symbol::new(&env, "content_moderated"))
+            })
+        })
+        .expect("content_moderated event not emitted");
+    let data: ContentModeratedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(
+        data,
+        ContentModeratedEvent {
+            content_id: 1,
+            previous: ModerationStatus::Active,
+            status: ModerationStatus::Removed,
+            reason: 42,
+            moderator: admin,
+        }
+    );
+}
@@ -0,0 +1,382 @@
+use crate::{
+    events::ReleaseScheduledEvent, CartItem, ContentAccess, ContentAccessClient, Error,
+    PreOrderError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_content_price(&creator, &1, &1_000);
+    client.set_release_ledger(&creator, &1, &1_500);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        creator,
+        buyer,
+    }
+}
+
+#[test]
+fn preorder_is_escrowed_and_locked_until_release() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+    assert_eq!(s.token.balance(&s.creator), 0);
+    assert_eq!(s.client.get_preorders(&s.creator, &1).len(), 1);
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+    assert_eq!(
+        s.client.try_verify_access(&s.buyer, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotYetReleased as u32
+        )))
+    );
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    s.client.verify_access(&s.buyer, &s.creator, &1);
+}
+
+#[test]
+fn settle_pays_creator_and_fee_after_release() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    let friend = Address::generate(&s.env);
+    s.client.gift_content(&s.buyer, &friend, &s.creator, &1, &0);
+
+    assert_eq!(
+        s.client.try_settle_preorders(&s.creator, &1, &50),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotYetReleased as u32
+        )))
+    );
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    s.client.settle_preorders(&s.creator, &1, &50);
+    assert_eq!(s.token.balance(&s.creator), 1_800);
+    assert_eq!(s.token.balance(&s.admin), 200);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert!(s.client.get_preorders(&s.creator, &1).is_empty());
+
+    // Sales after release go straight to the creator.
+    let late = Address::generate(&s.env);
+    s.client.gift_content(&s.buyer, &late, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&s.creator), 2_700);
+}
+
+#[test]
+fn cancel_refunds_every_preorder_in_full() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    let friend = Address::generate(&s.env);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    s.client.gift_content(&s.buyer, &friend, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&s.buyer), 8_000);
+
+    assert_eq!(s.client.cancel_release(&s.creator, &1, &50), 0);
+
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_receipt(&friend, &s.creator, &1), None);
+    assert_eq!(s.client.get_release_ledger(&s.creator, &1), Some(u64::MAX));
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+}
+
+#[test]
+fn rentals_start_at_release() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &1,
+        &vec![
+            &s.env,
+            crate::AccessOption {
+                duration_ledgers: Some(100),
+                price: 200,
+            },
+        ],
+    );
+    s.client.unlock_many(
+        &s.buyer,
+        &vec![
+            &s.env,
+            CartItem {
+                creator: s.creator.clone(),
+                content_id: 1,
+                option: 0,
+            },
+        ],
+    );
+    assert_eq!(s.token.balance(&s.client.address), 200);
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1599);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    s.env.ledger().with_mut(|li| li.sequence_number = 1600);
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+}
+
+#[test]
+fn refunding_a_preorder_returns_escrow() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    s.client
+        .refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &false);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert!(s.client.get_preorders(&s.creator, &1).is_empty());
+}
+
+#[test]
+fn postponing_emits_event() {
+    let s = setup();
+    s.client.set_release_ledger(&s.creator, &1, &2_000);
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (Symbol::new(&s.env, "release_postponed"), s.creator.clone()).into_val(&s.env)
+        })
+        .expect("release_postponed event not emitted");
+    let data: ReleaseScheduledEvent = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(
+        data,
+        ReleaseScheduledEvent {
+            content_id: 1,
+            previous: Some(1_500),
+            release_ledger: 2_000,
+        }
+    );
+}
+
+#[test]
+fn release_schedule_validation() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_set_release_ledger(&s.creator, &2, &1_000),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidReleaseLedger as u32
+        )))
+    );
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert_eq!(
+        s.client.try_set_release_ledger(&s.creator, &1, &3_000),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyReleased as u32
+        )))
+    );
+    assert_eq!(
+        s.client.try_cancel_release(&s.creator, &1, &50),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyReleased as u32
+        )))
+    );
+}
+
+#[test]
+fn settlement_uses_escrow_not_current_receipt() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &1,
+        &vec![
+            &s.env,
+            crate::AccessOption {
+                duration_ledgers: Some(100),
+                price: 200,
+            },
+            crate::AccessOption {
+                duration_ledgers: None,
+                price: 1_000,
+            },
+        ],
+    );
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    // The rental lapses after release, but the escrow is still unsettled.
+    s.env.ledger().with_mut(|li| li.sequence_number = 1600);
+    assert_eq!(
+        s.client.try_unlock_content(&s.buyer, &s.creator, &1, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            PreOrderError::EscrowPending as u32
+        )))
+    );
+
+    s.client.settle_preorders(&s.creator, &1, &50);
+    assert_eq!(s.token.balance(&s.creator), 200);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &1);
+    assert_eq!(s.token.balance(&s.creator), 1_200);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn unsettled_preorder_cannot_be_resold() {
+    let s = setup();
+    s.client.set_resale_royalty(&s.creator, &1, &Some(500));
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert_eq!(
+        s.client.try_list_access(&s.buyer, &s.creator, &1, &2_000),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotResellable as u32
+        )))
+    );
+
+    s.client.settle_preorders(&s.creator, &1, &50);
+    s.client.list_access(&s.buyer, &s.creator, &1, &2_000);
+    assert_eq!(s.token.balance(&s.creator), 1_000);
+}
+
+#[test]
+fn bundles_with_unreleased_members_cannot_be_bought() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &2, &500);
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &vec![&s.env, 1, 2], &1_200, &None);
+
+    assert_eq!(
+        s.client.try_unlock_bundle(&s.buyer, &bundle_id),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotYetReleased as u32
+        )))
+    );
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(s.token.balance(&s.creator), 1_200);
+}
+
+#[test]
+fn cancel_refunds_in_batches() {
+    let s = setup();
+    let friends = [
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    for friend in friends.iter() {
+        s.client.gift_content(&s.buyer, friend, &s.creator, &1, &0);
+    }
+
+    assert_eq!(s.client.cancel_release(&s.creator, &1, &2), 1);
+    assert_eq!(s.token.balance(&s.buyer), 9_000);
+    assert_eq!(s.client.get_receipt(&friends[0], &s.creator, &1), None);
+    assert_eq!(s.client.get_preorders(&s.creator, &1).len(), 1);
+    assert_eq!(
+        s.client.try_set_release_ledger(&s.creator, &1, &2_000),
+        Err(Ok(SorobanError::from_contract_error(
+            PreOrderError::RefundsPending as u32
+        )))
+    );
+
+    assert_eq!(s.client.cancel_release(&s.creator, &1, &2), 0);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    s.client.set_release_ledger(&s.creator, &1, &2_000);
+}
+
+#[test]
+fn settle_pays_out_in_batches() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    let friends = [
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    for friend in friends.iter() {
+        s.client.gift_content(&s.buyer, friend, &s.creator, &1, &0);
+    }
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert_eq!(s.client.settle_preorders(&s.creator, &1, &2), 1);
+    assert_eq!(s.token.balance(&s.creator), 1_800);
+    assert_eq!(
+        s.client.get_preorders(&s.creator, &1),
+        vec![&s.env, friends[2].clone()]
+    );
+
+    assert_eq!(s.client.settle_preorders(&s.creator, &1, &2), 0);
+    assert_eq!(s.token.balance(&s.creator), 2_700);
+    assert_eq!(s.token.balance(&s.admin), 300);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn refunding_one_preorder_keeps_the_rest_escrowed() {
+    let s = setup();
+    let friends = [
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    for friend in friends.iter() {
+        s.client.gift_content(&s.buyer, friend, &s.creator, &1, &0);
+    }
+
+    s.client
+        .refund_purchase(&s.creator, &friends[0], &s.creator, &1, &false);
+    assert_eq!(
+        s.client.get_preorders(&s.creator, &1),
+        vec![&s.env, friends[2].clone(), friends[1].clone()]
+    );
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1500);
+    assert_eq!(s.client.settle_preorders(&s.creator, &1, &50), 0);
+    assert_eq!(s.token.balance(&s.creator), 2_000);
+    assert_eq!(s.token.balance(&s.buyer), 8_000);
+}
+
+#[test]
+fn content_with_buyers_cannot_become_a_preorder() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &2, &500);
+    s.client.unlock_content(&s.buyer, &s.creator, &2, &0);
+
+    assert_eq!(
+        s.client.try_set_release_ledger(&s.creator, &2, &2_000),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyReleased as u32
+        )))
+    );
+    assert!(s.client.has_access(&s.buyer, &s.creator, &2));
+}
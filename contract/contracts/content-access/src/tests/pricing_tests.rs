@@ -0,0 +1,192 @@
+use crate::{
+    ContentAccess, ContentAccessClient, Error, PriceBound, PriceRule, PriceScope, PriceViolation,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, Error as SorobanError};
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let creator = Address::generate(env);
+    let client = ContentAccessClient::new(env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token);
+    (client, creator)
+}
+
+fn rule(min_price: Option<i128>, max_price: Option<i128>) -> PriceRule {
+    PriceRule {
+        min_price,
+        max_price,
+    }
+}
+
+#[test]
+fn most_specific_rule_decides_each_bound() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    client.set_price_rule(&PriceScope::Global, &rule(Some(10), Some(1_000)));
+    client.set_price_rule(&PriceScope::TrustLevel(0), &rule(None, Some(100)));
+    client.set_price_rule(&PriceScope::Category(7), &rule(Some(50), Some(500)));
+
+    // Unverified creator: trust-level cap, global floor.
+    assert_eq!(
+        client.try_set_content_price(&creator, &1, &101),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceExceedsTrustMax as u32
+        )))
+    );
+    assert_eq!(
+        client.try_set_content_price(&creator, &1, &5),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceBelowMin as u32
+        )))
+    );
+
+    // The category rule overrides both bounds for tagged content.
+    client.set_content_category(&creator, &1, &Some(7));
+    client.set_content_price(&creator, &1, &400);
+    assert_eq!(
+        client.try_set_content_price(&creator, &1, &501),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceExceedsCategoryMax as u32
+        )))
+    );
+    assert_eq!(
+        client.try_set_content_price(&creator, &1, &20),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceBelowCategoryMin as u32
+        )))
+    );
+
+    // Promoted creators fall through to the global cap.
+    client.set_trust_level(&creator, &1);
+    client.set_content_price(&creator, &2, &900);
+    assert_eq!(
+        client.try_set_content_price(&creator, &2, &1_001),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceExceedsMax as u32
+        )))
+    );
+}
+
+#[test]
+fn trust_floor_applies_to_bundles_and_options() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    client.set_price_rule(&PriceScope::TrustLevel(0), &rule(Some(20), None));
+
+    assert_eq!(
+        client.try_create_bundle(&creator, &vec![&env, 1u64, 2u64], &10, &None),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceBelowTrustMin as u32
+        )))
+    );
+    assert_eq!(
+        client.try_set_access_options(
+            &creator,
+            &1,
+            &vec![
+                &env,
+                crate::AccessOption {
+                    duration_ledgers: Some(10),
+                    price: 5,
+                },
+            ],
+        ),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceBelowTrustMin as u32
+        )))
+    );
+}
+
+#[test]
+fn prices_broken_by_new_rules_are_flagged() {
+    let env = Env::default();
+    let (client, creator) = setup(&env);
+    for id in 1..=3u64 {
+        client.register_content(
+            &creator,
+            &id,
+            &(100 * id as i128),
+            &BytesN::from_array(&env, &[0; 32]),
+        );
+    }
+    client.set_content_category(&creator, &3, &Some(2));
+
+    client.set_price_rule(&PriceScope::Global, &rule(None, Some(150)));
+    client.set_price_rule(&PriceScope::Category(2), &rule(None, Some(250)));
+
+    assert_eq!(client.get_price_violation(&creator, &1), None);
+    assert_eq!(
+        client.get_price_violation(&creator, &2),
+        Some(PriceViolation {
+            scope: PriceScope::Global,
+            bound: PriceBound::Cap,
+            limit: 150,
+        })
+    );
+    assert_eq!(
+        client.get_price_violation(&creator, &3),
+        Some(PriceViolation {
+            scope: PriceScope::Category(2),
+            bound: PriceBound::Cap,
+            limit: 250,
+        })
+    );
+    assert_eq!(client.get_content_price(&creator, &2), Some(200));
+    assert_eq!(
+        client.list_price_violations(&creator, &0, &10),
+        (vec![&env, 2u64, 3u64], 0)
+    );
+    assert_eq!(
+        client.list_price_violations(&creator, &0, &2),
+        (vec![&env, 2u64], 2)
+    );
+}
+
+#[test]
+fn global_rule_shares_storage_with_max_price() {
+    let env = Env::default();
+    let (client, _creator) = setup(&env);
+    client.set_max_price(&500);
+    assert_eq!(
+        client.get_price_rule(&PriceScope::Global),
+        rule(None, Some(500))
+    );
+
+    client.set_price_rule(&PriceScope::Global, &rule(Some(10), None));
+    assert_eq!(client.get_max_price(), None);
+    assert_eq!(
+        client.try_set_max_price(&5),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidPriceRule as u32
+        )))
+    );
+}
+
+#[test]
+fn invalid_rules_rejected() {
+    let env = Env::default();
+    let (client, _creator) = setup(&env);
+    let invalid = Err(Ok(SorobanError::from_contract_error(
+        Error::InvalidPriceRule as u32,
+    )));
+
+    assert_eq!(
+        client.try_set_price_rule(&PriceScope::Category(1), &rule(Some(100), Some(50))),
+        invalid
+    );
+    assert_eq!(
+        client.try_set_price_rule(&PriceScope::TrustLevel(0), &rule(Some(0), None)),
+        invalid
+    );
+
+    client.set_price_rule(&PriceScope::Category(1), &rule(Some(5), None));
+    client.set_price_rule(&PriceScope::Category(1), &rule(None, None));
+    assert_eq!(
+        client.get_price_rule(&PriceScope::Category(1)),
+        rule(None, None)
+    );
+}
@@ -0,0 +1,233 @@
+use crate::{events::PurchaseRefundedEvent, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    let minter = token::StellarAssetClient::new(&env, &token_address.address());
+    minter.mint(&buyer, &10_000);
+    minter.mint(&admin, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_fee_bps(&1_000);
+    client.set_content_price(&creator, &1, &1_000);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        creator,
+        buyer,
+    }
+}
+
+#[test]
+fn creator_refund_returns_net_and_revokes_access() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&s.creator), 900);
+
+    s.client
+        .refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &false);
+
+    assert_eq!(s.token.balance(&s.creator), 0);
+    assert_eq!(s.token.balance(&s.buyer), 9_900);
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+    assert_eq!(s.client.get_receipt(&s.buyer, &s.creator, &1), None);
+    assert_eq!(
+        s.client.try_verify_access(&s.buyer, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotBuyer as u32
+        )))
+    );
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (
+                    Symbol::new(&s.env, "purchase_refunded"),
+                    s.buyer.clone(),
+                    s.creator.clone(),
+                )
+                    .into_val(&s.env)
+        })
+        .expect("purchase_refunded event not emitted");
+    let data: PurchaseRefundedEvent = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(
+        data,
+        PurchaseRefundedEvent {
+            content_id: 1,
+            refunder: s.creator.clone(),
+            amount: 900,
+            fee_refunded: 0,
+        }
+    );
+}
+
+#[test]
+fn admin_refund_can_include_fee() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    s.client
+        .refund_purchase(&s.admin, &s.buyer, &s.creator, &1, &true);
+
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+    // The admin, as fee recipient, returns the fee; the creator the rest.
+    assert_eq!(s.token.balance(&s.admin), 10_000);
+    assert_eq!(s.token.balance(&s.creator), 0);
+
+    // Refunded content can be bought again.
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+}
+
+#[test]
+fn admin_refund_is_clawed_back_from_creator() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    let admin_before = s.token.balance(&s.admin);
+
+    s.client
+        .refund_purchase(&s.admin, &s.buyer, &s.creator, &1, &false);
+    assert!(s
+        .env
+        .auths()
+        .iter()
+        .any(|(address, _)| *address == s.creator));
+    assert_eq!(s.token.balance(&s.buyer), 10_000 - 100);
+    assert_eq!(s.token.balance(&s.admin), admin_before);
+    assert_eq!(s.token.balance(&s.creator), 0);
+}
+
+#[test]
+fn refunded_fee_comes_from_fee_recipient() {
+    let s = setup();
+    let treasury = Address::generate(&s.env);
+    s.client.set_fee_recipient(&treasury);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert_eq!(s.token.balance(&treasury), 100);
+
+    s.client
+        .refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &true);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+    assert_eq!(s.token.balance(&s.creator), 0);
+    assert_eq!(s.token.balance(&treasury), 0);
+}
+
+#[test]
+fn only_creator_or_admin_may_refund() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    assert_eq!(
+        s.client
+            .try_refund_purchase(&s.buyer, &s.buyer, &s.creator, &1, &false),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotRefunder as u32
+        )))
+    );
+}
+
+#[test]
+fn refund_requires_a_direct_purchase() {
+    let s = setup();
+    assert_eq!(
+        s.client
+            .try_refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &false),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotRefundable as u32
+        )))
+    );
+
+    // Bundle-granted access has no receipt of its own.
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &vec![&s.env, 2u64, 3], &100, &None);
+    s.client.unlock_bundle(&s.buyer, &bundle_id);
+    assert_eq!(
+        s.client
+            .try_refund_purchase(&s.creator, &s.buyer, &s.creator, &2, &false),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotRefundable as u32
+        )))
+    );
+}
+
+#[test]
+fn buyer_request_within_window_is_recorded() {
+    let s = setup();
+    s.client.set_refund_window(&100);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    s.env.ledger().with_mut(|li| li.sequence_number = 1050);
+    s.client.request_refund(&s.buyer, &s.creator, &1);
+    assert_eq!(
+        s.client.refund_request(&s.buyer, &s.creator, &1),
+        Some(1050)
+    );
+
+    s.client
+        .refund_purchase(&s.creator, &s.buyer, &s.creator, &1, &false);
+    assert_eq!(s.client.refund_request(&s.buyer, &s.creator, &1), None);
+}
+
+#[test]
+fn buyer_request_outside_window_rejected() {
+    let s = setup();
+    let closed = Err(Ok(SorobanError::from_contract_error(
+        Error::RefundWindowClosed as u32,
+    )));
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    // Requests are disabled until the admin sets a window.
+    assert_eq!(s.client.refund_window(), 0);
+    assert_eq!(
+        s.client.try_request_refund(&s.buyer, &s.creator, &1),
+        closed
+    );
+
+    s.client.set_refund_window(&100);
+    s.env.ledger().with_mut(|li| li.sequence_number = 1100);
+    assert_eq!(
+        s.client.try_request_refund(&s.buyer, &s.creator, &1),
+        closed
+    );
+}
+
+#[test]
+fn declined_request_keeps_access() {
+    let s = setup();
+    s.client.set_refund_window(&100);
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    s.client.request_refund(&s.buyer, &s.creator, &1);
+
+    s.client
+        .decline_refund(&s.creator, &s.buyer, &s.creator, &1);
+    assert_eq!(s.client.refund_request(&s.buyer, &s.creator, &1), None);
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+}
@@ -0,0 +1,156 @@
+use crate::{CartItem, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, BytesN, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+/// Creator-registry stand-in: an address is registered while an ID is stored.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_creator_id(env: Env, address: Address, creator_id: Option<u64>) {
+        match creator_id {
+            Some(id) => env.storage().instance().set(&address, &id),
+            None => env.storage().instance().remove(&address),
+        }
+    }
+
+    pub fn get_creator_id(env: Env, address: Address) -> Option<u64> {
+        env.storage().instance().get(&address)
+    }
+}
+
+fn setup(env: &Env) -> (ContentAccessClient<'_>, MockRegistryClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+    client.set_content_price(&creator, &1, &100);
+
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(env, &registry_id);
+    (client, registry, creator)
+}
+
+#[test]
+fn registry_check_is_off_by_default() {
+    let env = Env::default();
+    let (client, _registry, creator) = setup(&env);
+
+    assert_eq!(client.creator_registry(), None);
+    client.set_content_price(&creator, &2, &100);
+    client.unlock_content(&Address::generate(&env), &creator, &1, &0);
+}
+
+#[test]
+fn unregistered_creator_cannot_price_content() {
+    let env = Env::default();
+    let (client, registry, creator) = setup(&env);
+    client.set_creator_registry(&Some(registry.address.clone()));
+    assert_eq!(client.creator_registry(), Some(registry.address.clone()));
+
+    assert_eq!(
+        client.try_set_content_price(&creator, &2, &100),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+    assert_eq!(
+        client.try_register_content(&creator, &2, &100, &BytesN::from_array(&env, &[0; 32])),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+
+    registry.set_creator_id(&creator, &Some(7));
+    client.set_content_price(&creator, &2, &100);
+    assert_eq!(client.get_content_price(&creator, &2), Some(100));
+}
+
+#[test]
+fn unregistered_creator_cannot_sell_but_buyers_keep_access() {
+    let env = Env::default();
+    let (client, registry, creator) = setup(&env);
+    let buyer = Address::generate(&env);
+    let fan = Address::generate(&env);
+    registry.set_creator_id(&creator, &Some(7));
+    client.set_creator_registry(&Some(registry.address.clone()));
+    client.unlock_content(&buyer, &creator, &1, &0);
+
+    // Unregistering in the registry suspends new sales.
+    registry.set_creator_id(&creator, &None);
+    assert_eq!(
+        client.try_unlock_content(&fan, &creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+    assert_eq!(
+        client.try_gift_content(&fan, &Address::generate(&env), &creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+    assert_eq!(
+        client.try_unlock_many(
+            &fan,
+            &vec![
+                &env,
+                CartItem {
+                    creator: creator.clone(),
+                    content_id: 1,
+                    option: 0,
+                },
+            ],
+        ),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+    assert!(client.has_access(&buyer, &creator, &1));
+}
+
+#[test]
+fn disabling_registry_restores_open_pricing() {
+    let env = Env::default();
+    let (client, registry, creator) = setup(&env);
+    client.set_creator_registry(&Some(registry.address.clone()));
+    assert_eq!(
+        client.try_set_content_price(&creator, &2, &100),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::CreatorNotRegistered as u32
+        )))
+    );
+
+    client.set_creator_registry(&None);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "creator_registry_set"), client.admin()).into_val(&env)
+    );
+    assert_eq!(
+        TryIntoVal::<Env, Option<Address>>::try_into_val(&data, &env).unwrap(),
+        None
+    );
+    client.set_content_price(&creator, &2, &100);
+    client.unlock_content(&Address::generate(&env), &creator, &2, &0);
+}
@@ -0,0 +1,219 @@
+use crate::{
+    events::AccessResoldEvent, AccessOption, ContentAccess, ContentAccessClient, Error,
+    ModerationStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+    seller: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    let asset = token::StellarAssetClient::new(&env, &token_address.address());
+    asset.mint(&seller, &1_000);
+    asset.mint(&buyer, &1_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_content_price(&creator, &1, &100);
+    client.set_resale_royalty(&creator, &1, &Some(1_000));
+    client.unlock_content(&seller, &creator, &1, &0);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+        seller,
+        buyer,
+    }
+}
+
+#[test]
+fn resale_moves_access_and_pays_royalty() {
+    let s = setup();
+    s.client.list_access(&s.seller, &s.creator, &1, &300);
+    assert_eq!(s.client.get_listing(&s.seller, &s.creator, &1), Some(300));
+
+    s.client.buy_resale(&s.buyer, &s.seller, &s.creator, &1);
+
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    assert!(!s.client.has_access(&s.seller, &s.creator, &1));
+    assert_eq!(s.client.get_listing(&s.seller, &s.creator, &1), None);
+    assert_eq!(s.client.get_receipt(&s.seller, &s.creator, &1), None);
+    assert_eq!(s.token.balance(&s.buyer), 700);
+    // 100 from the original sale plus a 10% royalty on 300.
+    assert_eq!(s.token.balance(&s.creator), 130);
+    assert_eq!(s.token.balance(&s.seller), 900 + 270);
+
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (
+                    Symbol::new(&s.env, "access_resold"),
+                    s.seller.clone(),
+                    s.buyer.clone(),
+                )
+                    .into_val(&s.env)
+        })
+        .expect("access_resold event not emitted");
+    let data: AccessResoldEvent = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(
+        data,
+        AccessResoldEvent {
+            creator: s.creator.clone(),
+            content_id: 1,
+            price: 300,
+            royalty: 30,
+        }
+    );
+
+    // The new holder can list it again.
+    s.client.list_access(&s.buyer, &s.creator, &1, &500);
+}
+
+#[test]
+fn serial_moves_with_the_purchase() {
+    let s = setup();
+    s.client.set_supply_cap(&s.creator, &2, &10);
+    s.client.set_content_price(&s.creator, &2, &100);
+    s.client.set_resale_royalty(&s.creator, &2, &Some(0));
+    s.client.unlock_content(&s.seller, &s.creator, &2, &0);
+    s.client.list_access(&s.seller, &s.creator, &2, &100);
+
+    s.client.buy_resale(&s.buyer, &s.seller, &s.creator, &2);
+    assert_eq!(s.client.get_serial(&s.buyer, &s.creator, &2), Some(1));
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &2), Some(9));
+}
+
+#[test]
+fn cancelled_listing_cannot_be_bought() {
+    let s = setup();
+    s.client.list_access(&s.seller, &s.creator, &1, &300);
+    s.client.cancel_listing(&s.seller, &s.creator, &1);
+
+    let not_found = Err(Ok(SorobanError::from_contract_error(
+        Error::ListingNotFound as u32,
+    )));
+    assert_eq!(
+        s.client.try_buy_resale(&s.buyer, &s.seller, &s.creator, &1),
+        not_found
+    );
+    assert_eq!(
+        s.client.try_cancel_listing(&s.seller, &s.creator, &1),
+        not_found
+    );
+}
+
+#[test]
+fn resale_requires_opt_in_and_permanent_purchase() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &2,
+        &vec![
+            &s.env,
+            AccessOption {
+                duration_ledgers: Some(100),
+                price: 10,
+            },
+        ],
+    );
+    s.client.unlock_content(&s.seller, &s.creator, &2, &0);
+
+    assert_eq!(
+        s.client.try_list_access(&s.seller, &s.creator, &2, &50),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ResaleDisabled as u32
+        )))
+    );
+    s.client.set_resale_royalty(&s.creator, &2, &Some(500));
+    assert_eq!(
+        s.client.try_list_access(&s.seller, &s.creator, &2, &50),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotResellable as u32
+        )))
+    );
+    assert_eq!(
+        s.client.try_list_access(&s.seller, &s.creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidPrice as u32
+        )))
+    );
+    assert_eq!(
+        s.client
+            .try_set_resale_royalty(&s.creator, &1, &Some(10_001)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidFeeBps as u32
+        )))
+    );
+}
+
+#[test]
+fn stale_listings_are_rejected() {
+    let s = setup();
+    s.client.list_access(&s.seller, &s.creator, &1, &300);
+
+    // Disabling resale freezes existing listings.
+    s.client.set_resale_royalty(&s.creator, &1, &None);
+    assert_eq!(
+        s.client.try_buy_resale(&s.buyer, &s.seller, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ResaleDisabled as u32
+        )))
+    );
+    s.client.set_resale_royalty(&s.creator, &1, &Some(1_000));
+
+    s.client
+        .moderate_content(&s.creator, &1, &ModerationStatus::Suspended, &0);
+    assert_eq!(
+        s.client.try_buy_resale(&s.buyer, &s.seller, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ContentModerated as u32
+        )))
+    );
+    s.client
+        .moderate_content(&s.creator, &1, &ModerationStatus::Active, &0);
+
+    // A refund drops the listing along with the purchase.
+    s.client
+        .refund_purchase(&s.creator, &s.seller, &s.creator, &1, &false);
+    assert_eq!(s.client.get_listing(&s.seller, &s.creator, &1), None);
+}
+
+#[test]
+fn owners_cannot_buy_twice() {
+    let s = setup();
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+    s.client.list_access(&s.seller, &s.creator, &1, &300);
+
+    assert_eq!(
+        s.client.try_buy_resale(&s.buyer, &s.seller, &s.creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyHasAccess as u32
+        )))
+    );
+}
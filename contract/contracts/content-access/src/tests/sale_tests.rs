@@ -0,0 +1,239 @@
+use crate::{
+    AccessOption, ContentAccess, ContentAccessClient, Error, PriceRule, PriceScope, Sale,
+    SaleDiscount,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_content_price(&creator, &1, &1_000);
+    client.set_content_price(&creator, &2, &400);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+        buyer,
+    }
+}
+
+fn sale(discount: SaleDiscount, start_ledger: u64, end_ledger: u64) -> Sale {
+    Sale {
+        discount,
+        start_ledger,
+        end_ledger,
+    }
+}
+
+fn set_ledger(env: &Env, sequence_number: u32) {
+    env.ledger()
+        .with_mut(|li| li.sequence_number = sequence_number);
+}
+
+#[test]
+fn sale_applies_only_inside_its_window() {
+    let s = setup();
+    s.client.set_sale(
+        &s.creator,
+        &Some(1),
+        &Some(sale(SaleDiscount::Bps(2_500), 1_100, 1_200)),
+    );
+
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(1_000));
+    set_ledger(&s.env, 1_100);
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(750));
+    assert_eq!(s.client.get_list_price(&s.creator, &1), Some(1_000));
+    set_ledger(&s.env, 1_200);
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(1_000));
+    assert_eq!(s.client.get_active_sale(&s.creator, &1), None);
+}
+
+#[test]
+fn unlock_charges_sale_price_and_reports_both_prices() {
+    let s = setup();
+    s.client.set_sale(
+        &s.creator,
+        &Some(1),
+        &Some(sale(SaleDiscount::Price(vec![&s.env, 600]), 1_000, 1_100)),
+    );
+    s.client.unlock_content(&s.buyer, &s.creator, &1, &0);
+
+    assert_eq!(s.token.balance(&s.creator), 600);
+    assert_eq!(
+        s.client
+            .get_receipt(&s.buyer, &s.creator, &1)
+            .unwrap()
+            .amount,
+        600
+    );
+    let event = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|event| {
+            event.1
+                == (
+                    Symbol::new(&s.env, "content_unlocked"),
+                    s.buyer.clone(),
+                    s.creator.clone(),
+                )
+                    .into_val(&s.env)
+        })
+        .expect("content_unlocked event not emitted");
+    let data: (u64, i128, i128, i128, i128) = event.2.try_into_val(&s.env).unwrap();
+    assert_eq!(data, (1, 1_000, 600, 0, 600));
+}
+
+#[test]
+fn item_sale_overrides_creator_wide_sale() {
+    let s = setup();
+    s.client.set_sale(
+        &s.creator,
+        &None,
+        &Some(sale(SaleDiscount::Bps(5_000), 1_000, 2_000)),
+    );
+    s.client.set_sale(
+        &s.creator,
+        &Some(1),
+        &Some(sale(SaleDiscount::Bps(1_000), 1_000, 2_000)),
+    );
+
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(900));
+    assert_eq!(s.client.get_content_price(&s.creator, &2), Some(200));
+
+    // Cancelling the item sale falls back to the creator-wide one.
+    s.client.set_sale(&s.creator, &Some(1), &None);
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(500));
+}
+
+#[test]
+fn fixed_sale_price_applies_per_option() {
+    let s = setup();
+    s.client.set_access_options(
+        &s.creator,
+        &3,
+        &vec![
+            &s.env,
+            AccessOption {
+                duration_ledgers: Some(100),
+                price: 50,
+            },
+            AccessOption {
+                duration_ledgers: None,
+                price: 500,
+            },
+        ],
+    );
+    s.client.set_sale(
+        &s.creator,
+        &Some(3),
+        &Some(sale(
+            SaleDiscount::Price(vec![&s.env, 30, 200]),
+            1_000,
+            1_100,
+        )),
+    );
+
+    // Each option gets its own sale price.
+    s.client.unlock_content(&s.buyer, &s.creator, &3, &0);
+    assert_eq!(s.token.balance(&s.creator), 30);
+
+    let other = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&other, &1_000);
+    s.client.unlock_content(&other, &s.creator, &3, &1);
+    assert_eq!(s.token.balance(&s.creator), 230);
+
+    // Options without a sale price, or listed below it, keep their list price.
+    s.client.set_sale(
+        &s.creator,
+        &Some(3),
+        &Some(sale(SaleDiscount::Price(vec![&s.env, 80]), 1_000, 1_100)),
+    );
+    for option in [0, 1] {
+        let fan = Address::generate(&s.env);
+        token::StellarAssetClient::new(&s.env, &s.token.address).mint(&fan, &1_000);
+        s.client.unlock_content(&fan, &s.creator, &3, &option);
+    }
+    assert_eq!(s.token.balance(&s.creator), 230 + 50 + 500);
+}
+
+#[test]
+fn sale_price_respects_admin_floor() {
+    let s = setup();
+    s.client.set_price_rule(
+        &PriceScope::Global,
+        &PriceRule {
+            min_price: Some(300),
+            max_price: None,
+        },
+    );
+    s.client.set_sale(
+        &s.creator,
+        &None,
+        &Some(sale(SaleDiscount::Bps(10_000), 1_000, 1_100)),
+    );
+
+    assert_eq!(s.client.get_content_price(&s.creator, &1), Some(300));
+    // Items listed under the floor keep their list price.
+    s.client.set_price_rule(
+        &PriceScope::Global,
+        &PriceRule {
+            min_price: Some(500),
+            max_price: None,
+        },
+    );
+    assert_eq!(s.client.get_content_price(&s.creator, &2), Some(400));
+}
+
+#[test]
+fn invalid_sales_rejected() {
+    let s = setup();
+    let invalid = Err(Ok(SorobanError::from_contract_error(
+        Error::InvalidSale as u32,
+    )));
+
+    for bad in [
+        sale(SaleDiscount::Bps(0), 1_000, 1_100),
+        sale(SaleDiscount::Bps(10_001), 1_000, 1_100),
+        sale(SaleDiscount::Price(vec![&s.env, 0]), 1_000, 1_100),
+        sale(SaleDiscount::Price(vec![&s.env]), 1_000, 1_100),
+        sale(
+            SaleDiscount::Price(vec![&s.env, 100, 100, 100, 100, 100, 100, 100, 100, 100]),
+            1_000,
+            1_100,
+        ),
+        sale(SaleDiscount::Price(vec![&s.env, 100]), 1_100, 1_100),
+        sale(SaleDiscount::Price(vec![&s.env, 100]), 900, 1_000),
+    ] {
+        assert_eq!(
+            s.client.try_set_sale(&s.creator, &Some(1), &Some(bad)),
+            invalid
+        );
+    }
+}
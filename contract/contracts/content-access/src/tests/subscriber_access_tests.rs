@@ -0,0 +1,247 @@
+use crate::{events::SubscriberAccessEvent, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal, Val, Vec,
+};
+
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+/// Subscription contract stand-in: a fan is subscribed while a tier is stored.
+/// Counts `is_subscriber` calls.
+#[contract]
+pub struct MockSubscription;
+
+const CALLS: Symbol = symbol_short!("calls");
+
+#[contractimpl]
+impl MockSubscription {
+    pub fn set_tier(env: Env, fan: Address, creator: Address, tier: Option<u32>) {
+        match tier {
+            Some(tier) => env.storage().instance().set(&(fan, creator), &tier),
+            None => env.storage().instance().remove(&(fan, creator)),
+        }
+    }
+
+    pub fn is_subscriber(env: Env, fan: Address, creator: Address) -> bool {
+        let calls: u32 = env.storage().instance().get(&CALLS).unwrap_or(0);
+        env.storage().instance().set(&CALLS, &(calls + 1));
+        env.storage().instance().has(&(fan, creator))
+    }
+
+    pub fn is_subscriber_calls(env: Env) -> u32 {
+        env.storage().instance().get(&CALLS).unwrap_or(0)
+    }
+
+    pub fn subscriber_tier(env: Env, fan: Address, creator: Address) -> Option<u32> {
+        env.storage().instance().get(&(fan, creator))
+    }
+}
+
+fn setup(
+    env: &Env,
+) -> (
+    ContentAccessClient<'_>,
+    MockSubscriptionClient<'_>,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let fan = Address::generate(env);
+    let token_address = env.register_contract(None, MockToken);
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(env, &contract_id);
+    client.initialize(&admin, &token_address);
+
+    let subscription_id = env.register_contract(None, MockSubscription);
+    client.set_subscription_contract(&subscription_id);
+    let subscription = MockSubscriptionClient::new(env, &subscription_id);
+    (client, subscription, creator, fan)
+}
+
+#[test]
+fn subscriber_gets_access_to_included_content() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.set_content_price(&creator, &1, &100);
+    client.include_for_subscribers(&creator, &1, &None);
+    assert!(!client.has_access(&fan, &creator, &1));
+
+    subscription.set_tier(&fan, &creator, &Some(0));
+    assert!(client.has_access(&fan, &creator, &1));
+    client.verify_access(&fan, &creator, &1);
+
+    // Content not included for subscribers stays locked.
+    client.set_content_price(&creator, &2, &100);
+    assert!(!client.has_access(&fan, &creator, &2));
+}
+
+#[test]
+fn subscription_to_other_creator_does_not_unlock() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.include_for_subscribers(&creator, &1, &None);
+    subscription.set_tier(&fan, &Address::generate(&env), &Some(5));
+    assert!(!client.has_access(&fan, &creator, &1));
+}
+
+#[test]
+fn minimum_tier_is_enforced() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.include_for_subscribers(&creator, &1, &Some(2));
+    assert_eq!(client.subscriber_access(&creator, &1), Some(2));
+
+    subscription.set_tier(&fan, &creator, &Some(1));
+    assert!(!client.has_access(&fan, &creator, &1));
+    assert_eq!(
+        client.try_verify_access(&fan, &creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotBuyer as u32
+        )))
+    );
+
+    subscription.set_tier(&fan, &creator, &Some(2));
+    assert!(client.has_access(&fan, &creator, &1));
+    // The tier lookup alone decides tiered content.
+    assert_eq!(subscription.is_subscriber_calls(), 0);
+}
+
+#[test]
+fn lapsed_subscription_loses_access() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.include_for_subscribers(&creator, &1, &None);
+    subscription.set_tier(&fan, &creator, &Some(0));
+    assert!(client.has_access(&fan, &creator, &1));
+
+    subscription.set_tier(&fan, &creator, &None);
+    assert!(!client.has_access(&fan, &creator, &1));
+}
+
+#[test]
+fn excluded_content_is_no_longer_covered() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.include_for_subscribers(&creator, &1, &None);
+    subscription.set_tier(&fan, &creator, &Some(0));
+    client.exclude_from_subscribers(&creator, &1);
+
+    assert_eq!(client.subscriber_access(&creator, &1), None);
+    assert!(!client.has_access(&fan, &creator, &1));
+}
+
+#[test]
+fn expired_purchase_falls_back_to_subscription() {
+    let env = Env::default();
+    let (client, subscription, creator, fan) = setup(&env);
+
+    client.set_access_options(
+        &creator,
+        &1,
+        &soroban_sdk::vec![
+            &env,
+            crate::AccessOption {
+                duration_ledgers: Some(10),
+                price: 100,
+            },
+        ],
+    );
+    client.unlock_content(&fan, &creator, &1, &0);
+    client.include_for_subscribers(&creator, &1, &None);
+    env.ledger().with_mut(|li| li.sequence_number = 1010);
+
+    assert_eq!(
+        client.try_verify_access(&fan, &creator, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PurchaseExpired as u32
+        )))
+    );
+    subscription.set_tier(&fan, &creator, &Some(0));
+    client.verify_access(&fan, &creator, &1);
+}
+
+#[test]
+fn no_subscription_contract_means_no_subscriber_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let token_address = env.register_contract(None, MockToken);
+    let client = ContentAccessClient::new(&env, &env.register_contract(None, ContentAccess));
+    client.initialize(&admin, &token_address);
+
+    client.include_for_subscribers(&creator, &1, &None);
+    assert_eq!(client.subscription_contract(), None);
+    assert!(!client.has_access(&fan, &creator, &1));
+}
+
+/// Data of the last event published under `topics`.
+fn last_event<T: soroban_sdk::TryFromVal<Env, Val>>(env: &Env, topics: Vec<Val>) -> T {
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.1 == topics)
+        .last()
+        .expect("event not emitted");
+    event.2.try_into_val(env).unwrap()
+}
+
+#[test]
+fn config_changes_emit_events() {
+    let env = Env::default();
+    let (client, _, creator, _) = setup(&env);
+    let admin = client.admin();
+
+    let subscription = Address::generate(&env);
+    client.set_subscription_contract(&subscription);
+    let topics = (Symbol::new(&env, "subscription_contract_set"), admin).into_val(&env);
+    assert_eq!(last_event::<Address>(&env, topics), subscription);
+
+    client.include_for_subscribers(&creator, &1, &None);
+    let topics = (Symbol::new(&env, "subscriber_access_set"), creator.clone()).into_val(&env);
+    assert_eq!(
+        last_event::<SubscriberAccessEvent>(&env, topics),
+        SubscriberAccessEvent {
+            creator: creator.clone(),
+            content_id: 1,
+            min_tier: None,
+        }
+    );
+
+    client.exclude_from_subscribers(&creator, &1);
+    let topics = (
+        Symbol::new(&env, "subscriber_access_removed"),
+        creator.clone(),
+    )
+        .into_val(&env);
+    assert_eq!(
+        last_event::<SubscriberAccessEvent>(&env, topics),
+        SubscriberAccessEvent {
+            creator,
+            content_id: 1,
+            min_tier: None,
+        }
+    );
+}
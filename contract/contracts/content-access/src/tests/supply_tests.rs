@@ -0,0 +1,224 @@
+use crate::{AccessOption, ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, Error as SorobanError,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_access_options(
+        &creator,
+        &1,
+        &vec![
+            &env,
+            AccessOption {
+                duration_ledgers: None,
+                price: 100,
+            },
+            AccessOption {
+                duration_ledgers: Some(50),
+                price: 10,
+            },
+        ],
+    );
+    client.set_supply_cap(&creator, &1, &2);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+    }
+}
+
+fn funded_buyer(s: &Setup) -> Address {
+    let buyer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&buyer, &1_000);
+    buyer
+}
+
+#[test]
+fn copies_are_numbered_until_sold_out() {
+    let s = setup();
+    let first = funded_buyer(&s);
+    let second = funded_buyer(&s);
+    let third = funded_buyer(&s);
+
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(2));
+    s.client.unlock_content(&first, &s.creator, &1, &0);
+    s.client.gift_content(&first, &second, &s.creator, &1, &0);
+
+    assert_eq!(s.client.get_serial(&first, &s.creator, &1), Some(1));
+    assert_eq!(s.client.get_serial(&second, &s.creator, &1), Some(2));
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(0));
+    assert_eq!(
+        s.client.try_unlock_content(&third, &s.creator, &1, &0),
+        Err(Ok(SorobanError::from_contract_error(Error::SoldOut as u32)))
+    );
+    assert_eq!(s.token.balance(&third), 1_000);
+}
+
+#[test]
+fn rentals_do_not_consume_serials() {
+    let s = setup();
+    let renter = funded_buyer(&s);
+
+    s.client.unlock_content(&renter, &s.creator, &1, &1);
+    assert_eq!(s.client.get_serial(&renter, &s.creator, &1), None);
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(2));
+
+    // Once the rental expires the buyer can take a numbered permanent copy.
+    s.env.ledger().with_mut(|li| li.sequence_number = 1050);
+    s.client.unlock_content(&renter, &s.creator, &1, &0);
+    assert_eq!(s.client.get_serial(&renter, &s.creator, &1), Some(1));
+}
+
+#[test]
+fn refunded_serials_are_retired() {
+    let s = setup();
+    let buyer = funded_buyer(&s);
+    let other = funded_buyer(&s);
+    s.client.unlock_content(&buyer, &s.creator, &1, &0);
+    s.client
+        .refund_purchase(&s.creator, &buyer, &s.creator, &1, &false);
+
+    assert_eq!(s.client.get_serial(&buyer, &s.creator, &1), None);
+    s.client.unlock_content(&other, &s.creator, &1, &0);
+    assert_eq!(s.client.get_serial(&other, &s.creator, &1), Some(2));
+}
+
+#[test]
+fn uncapped_content_has_no_serials() {
+    let s = setup();
+    let buyer = funded_buyer(&s);
+    s.client.set_content_price(&s.creator, &2, &100);
+    s.client.unlock_content(&buyer, &s.creator, &2, &0);
+
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &2), None);
+    assert_eq!(s.client.get_serial(&buyer, &s.creator, &2), None);
+}
+
+#[test]
+fn cap_cannot_grow_after_first_sale() {
+    let s = setup();
+    let invalid = Err(Ok(SorobanError::from_contract_error(
+        Error::InvalidSupplyCap as u32,
+    )));
+    assert_eq!(s.client.try_set_supply_cap(&s.creator, &1, &0), invalid);
+
+    // Before any sale the cap may still be raised.
+    s.client.set_supply_cap(&s.creator, &1, &3);
+    s.client
+        .unlock_content(&funded_buyer(&s), &s.creator, &1, &0);
+
+    assert_eq!(s.client.try_set_supply_cap(&s.creator, &1, &4), invalid);
+    s.client.set_supply_cap(&s.creator, &1, &1);
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(0));
+}
+
+#[test]
+fn cap_counts_copies_sold_before_it() {
+    let s = setup();
+    let early = [funded_buyer(&s), funded_buyer(&s)];
+    s.client.set_content_price(&s.creator, &2, &100);
+    for buyer in early.iter() {
+        s.client.unlock_content(buyer, &s.creator, &2, &0);
+    }
+
+    assert_eq!(
+        s.client.try_set_supply_cap(&s.creator, &2, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidSupplyCap as u32
+        )))
+    );
+    s.client.set_supply_cap(&s.creator, &2, &3);
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &2), Some(1));
+    assert_eq!(s.client.get_serial(&early[0], &s.creator, &2), None);
+
+    let last = funded_buyer(&s);
+    s.client.unlock_content(&last, &s.creator, &2, &0);
+    assert_eq!(s.client.get_serial(&last, &s.creator, &2), Some(3));
+    assert_eq!(
+        s.client
+            .try_unlock_content(&funded_buyer(&s), &s.creator, &2, &0),
+        Err(Ok(SorobanError::from_contract_error(Error::SoldOut as u32)))
+    );
+}
+
+#[test]
+fn cap_set_before_sales_starts_at_serial_one() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &2, &100);
+    s.client.set_supply_cap(&s.creator, &2, &3);
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &2), Some(3));
+
+    let buyer = funded_buyer(&s);
+    s.client.unlock_content(&buyer, &s.creator, &2, &0);
+    assert_eq!(s.client.get_serial(&buyer, &s.creator, &2), Some(1));
+}
+
+#[test]
+fn limited_editions_cannot_be_bundled() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &2, &50);
+    assert_eq!(
+        s.client
+            .try_create_bundle(&s.creator, &vec![&s.env, 1, 2], &120, &None),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidBundle as u32
+        )))
+    );
+
+    let bundle_id = s
+        .client
+        .create_bundle(&s.creator, &vec![&s.env, 2], &50, &None);
+    assert_eq!(
+        s.client.try_add_to_bundle(&bundle_id, &1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidBundle as u32
+        )))
+    );
+    assert_eq!(
+        s.client.try_set_supply_cap(&s.creator, &2, &10),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidSupplyCap as u32
+        )))
+    );
+}
+
+#[test]
+fn rentals_stay_available_after_sell_out() {
+    let s = setup();
+    s.client
+        .unlock_content(&funded_buyer(&s), &s.creator, &1, &0);
+    s.client
+        .unlock_content(&funded_buyer(&s), &s.creator, &1, &0);
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(0));
+
+    // The cap limits owned copies only; renters are not counted.
+    for _ in 0..3 {
+        let renter = funded_buyer(&s);
+        s.client.unlock_content(&renter, &s.creator, &1, &1);
+        assert!(s.client.has_access(&renter, &s.creator, &1));
+        assert_eq!(s.client.get_serial(&renter, &s.creator, &1), None);
+    }
+    assert_eq!(s.client.get_remaining_supply(&s.creator, &1), Some(0));
+}
@@ -0,0 +1,141 @@
+use crate::{ContentAccess, ContentAccessClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, Error as SorobanError, IntoVal, Symbol, TryIntoVal,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    creator: Address,
+    buyer: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+    client.set_content_price(&creator, &1, &1_000);
+    client.set_pay_what_you_want(&creator, &1, &true);
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        creator,
+        buyer,
+    }
+}
+
+#[test]
+fn overpayment_is_recorded_as_a_tip() {
+    let s = setup();
+    s.client
+        .unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_500);
+
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    assert_eq!(s.token.balance(&s.creator), 1_500);
+    assert_eq!(s.client.get_tip_total(&s.creator), 500);
+    // Only the price is refundable.
+    assert_eq!(
+        s.client
+            .get_receipt(&s.buyer, &s.creator, &1)
+            .unwrap()
+            .amount,
+        1_000
+    );
+
+    let tipped = s.env.events().all().iter().find(|event| {
+        event.1
+            == (
+                Symbol::new(&s.env, "content_tipped"),
+                s.buyer.clone(),
+                s.creator.clone(),
+            )
+                .into_val(&s.env)
+    });
+    let data: (u64, i128, i128) = tipped
+        .expect("content_tipped event not emitted")
+        .2
+        .try_into_val(&s.env)
+        .unwrap();
+    assert_eq!(data, (1, 500, 0));
+}
+
+#[test]
+fn tip_fee_is_configured_separately() {
+    let s = setup();
+    s.client.set_fee_bps(&1_000);
+    s.client.set_tip_fee_bps(&200);
+    assert_eq!(s.client.tip_fee_bps(), 200);
+
+    s.client
+        .unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_500);
+    // 10% of the 1_000 price plus 2% of the 500 tip.
+    assert_eq!(s.token.balance(&s.admin), 100 + 10);
+    assert_eq!(s.token.balance(&s.creator), 900 + 490);
+
+    assert_eq!(
+        s.client.try_set_tip_fee_bps(&10_001),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidFeeBps as u32
+        )))
+    );
+}
+
+#[test]
+fn amount_must_cover_the_floor() {
+    let s = setup();
+    assert_eq!(
+        s.client
+            .try_unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &999),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AmountBelowPrice as u32
+        )))
+    );
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+
+    // Paying exactly the floor is not a tip.
+    s.client
+        .unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_000);
+    assert_eq!(s.client.get_tip_total(&s.creator), 0);
+    assert_eq!(
+        s.client
+            .try_unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_000),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::AlreadyHasAccess as u32
+        )))
+    );
+}
+
+#[test]
+fn fixed_price_content_rejects_tips() {
+    let s = setup();
+    s.client.set_pay_what_you_want(&s.creator, &1, &false);
+    assert!(!s.client.is_pay_what_you_want(&s.creator, &1));
+
+    assert_eq!(
+        s.client
+            .try_unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_500),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::TipsDisabled as u32
+        )))
+    );
+    s.client
+        .unlock_content_with_amount(&s.buyer, &s.creator, &1, &0, &1_000);
+    assert_eq!(s.token.balance(&s.creator), 1_000);
+}
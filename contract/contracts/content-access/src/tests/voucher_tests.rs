@@ -0,0 +1,243 @@
+use crate::{ContentAccess, ContentAccessClient, Error, PriceRule, PriceScope, Voucher};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Error as SorobanError,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: ContentAccessClient<'a>,
+    token: token::Client<'a>,
+    creator: Address,
+    buyer: Address,
+    signing_key: SigningKey,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &token_address.address()).mint(&buyer, &10_000);
+
+    let contract_id = env.register_contract(None, ContentAccess);
+    let client = ContentAccessClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_address.address());
+
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    client.set_voucher_key(
+        &creator,
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+    );
+
+    let token = token::Client::new(&env, &token_address.address());
+    Setup {
+        env,
+        client,
+        token,
+        creator,
+        buyer,
+        signing_key,
+    }
+}
+
+fn voucher(s: &Setup, nonce: u64) -> Voucher {
+    Voucher {
+        creator: s.creator.clone(),
+        content_id: 1,
+        price: 0,
+        duration_ledgers: None,
+        expires_at: 2000,
+        nonce,
+        max_uses: 1,
+        buyer: None,
+    }
+}
+
+fn sign(s: &Setup, key: &SigningKey, voucher: &Voucher) -> BytesN<64> {
+    let mut message = [0u8; 512];
+    let bytes = s.client.voucher_message(voucher);
+    let len = bytes.len() as usize;
+    bytes.copy_into_slice(&mut message[..len]);
+    BytesN::from_array(&s.env, &key.sign(&message[..len]).to_bytes())
+}
+
+#[test]
+fn free_voucher_grants_access() {
+    let s = setup();
+    let v = voucher(&s, 1);
+    s.client
+        .redeem_voucher(&s.buyer, &v, &sign(&s, &s.signing_key, &v));
+
+    assert!(s.client.has_access(&s.buyer, &s.creator, &1));
+    assert_eq!(s.client.voucher_uses(&s.creator, &1), 1);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+}
+
+#[test]
+fn discounted_voucher_charges_override_price() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &1_000);
+    let v = Voucher {
+        price: 250,
+        duration_ledgers: Some(100),
+        ..voucher(&s, 1)
+    };
+    s.client
+        .redeem_voucher(&s.buyer, &v, &sign(&s, &s.signing_key, &v));
+
+    assert_eq!(s.token.balance(&s.creator), 250);
+    s.env.ledger().with_mut(|li| li.sequence_number = 1100);
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+}
+
+#[test]
+fn paid_voucher_cannot_undercut_price_floor() {
+    let s = setup();
+    s.client.set_content_price(&s.creator, &1, &1_000);
+    s.client.set_price_rule(
+        &PriceScope::Global,
+        &PriceRule {
+            min_price: Some(300),
+            max_price: None,
+        },
+    );
+    let cheap = Voucher {
+        price: 250,
+        ..voucher(&s, 1)
+    };
+    assert_eq!(
+        s.client
+            .try_redeem_voucher(&s.buyer, &cheap, &sign(&s, &s.signing_key, &cheap)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PriceBelowMin as u32
+        )))
+    );
+    assert_eq!(s.client.voucher_uses(&s.creator, &1), 0);
+
+    // Vouchers at the floor, and free ones, are still honoured.
+    let at_floor = Voucher {
+        price: 300,
+        ..voucher(&s, 2)
+    };
+    s.client
+        .redeem_voucher(&s.buyer, &at_floor, &sign(&s, &s.signing_key, &at_floor));
+    assert_eq!(s.token.balance(&s.creator), 300);
+    let other = Address::generate(&s.env);
+    let free = voucher(&s, 3);
+    s.client
+        .redeem_voucher(&other, &free, &sign(&s, &s.signing_key, &free));
+    assert!(s.client.has_access(&other, &s.creator, &1));
+}
+
+#[test]
+fn single_use_voucher_cannot_be_replayed() {
+    let s = setup();
+    let v = voucher(&s, 1);
+    let signature = sign(&s, &s.signing_key, &v);
+    s.client.redeem_voucher(&s.buyer, &v, &signature);
+
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_redeem_voucher(&other, &v, &signature),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::VoucherExhausted as u32
+        )))
+    );
+}
+
+#[test]
+fn multi_use_voucher_counts_redemptions() {
+    let s = setup();
+    let v = Voucher {
+        max_uses: 2,
+        ..voucher(&s, 9)
+    };
+    let signature = sign(&s, &s.signing_key, &v);
+    s.client.redeem_voucher(&s.buyer, &v, &signature);
+    // Re-redeeming while access is valid does not consume a use.
+    s.client.redeem_voucher(&s.buyer, &v, &signature);
+    assert_eq!(s.client.voucher_uses(&s.creator, &9), 1);
+
+    s.client
+        .redeem_voucher(&Address::generate(&s.env), &v, &signature);
+    assert_eq!(
+        s.client
+            .try_redeem_voucher(&Address::generate(&s.env), &v, &signature),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::VoucherExhausted as u32
+        )))
+    );
+}
+
+#[test]
+fn bound_voucher_rejects_other_buyers() {
+    let s = setup();
+    let v = Voucher {
+        buyer: Some(s.buyer.clone()),
+        ..voucher(&s, 1)
+    };
+    let signature = sign(&s, &s.signing_key, &v);
+    assert_eq!(
+        s.client
+            .try_redeem_voucher(&Address::generate(&s.env), &v, &signature),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::VoucherNotForBuyer as u32
+        )))
+    );
+    s.client.redeem_voucher(&s.buyer, &v, &signature);
+}
+
+#[test]
+fn expired_voucher_rejected() {
+    let s = setup();
+    let v = voucher(&s, 1);
+    let signature = sign(&s, &s.signing_key, &v);
+    s.env.ledger().with_mut(|li| li.sequence_number = 2000);
+    assert_eq!(
+        s.client.try_redeem_voucher(&s.buyer, &v, &signature),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::VoucherExpired as u32
+        )))
+    );
+}
+
+#[test]
+fn tampered_or_foreign_signature_rejected() {
+    let s = setup();
+    let v = voucher(&s, 1);
+    let signature = sign(&s, &s.signing_key, &v);
+    let tampered = Voucher {
+        content_id: 2,
+        ..v.clone()
+    };
+    assert!(s
+        .client
+        .try_redeem_voucher(&s.buyer, &tampered, &signature)
+        .is_err());
+
+    let forged = sign(&s, &SigningKey::from_bytes(&[8; 32]), &v);
+    assert!(s.client.try_redeem_voucher(&s.buyer, &v, &forged).is_err());
+    assert!(!s.client.has_access(&s.buyer, &s.creator, &1));
+}
+
+#[test]
+fn voucher_requires_registered_key() {
+    let s = setup();
+    let v = Voucher {
+        creator: Address::generate(&s.env),
+        ..voucher(&s, 1)
+    };
+    let signature = sign(&s, &s.signing_key, &v);
+    assert_eq!(
+        s.client.try_redeem_voucher(&s.buyer, &v, &signature),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::VoucherKeyNotSet as u32
+        )))
+    );
+}
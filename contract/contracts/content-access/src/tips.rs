@@ -0,0 +1,47 @@
+//! Pay-what-you-want pricing: buyers may pay more than the price, and the
+//! excess is a tip.
+//!
+//! Tips go straight to the creator, minus the separate tip fee, even for
+//! pre-orders. They are kept out of the purchase `Receipt`, so they earn no
+//! bundle credit and are never refunded.
+
+use crate::{fee_recipient, token_client, DataKey};
+use soroban_sdk::{Address, Env};
+
+pub fn is_enabled(env: &Env, creator: &Address, content_id: u64) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::PayWhatYouWant(creator.clone(), content_id))
+}
+
+pub fn fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TipFeeBps)
+        .unwrap_or(0)
+}
+
+pub fn total(env: &Env, creator: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TipTotal(creator.clone()))
+        .unwrap_or(0)
+}
+
+/// Move `tip` from `payer` to `creator`, less the tip fee, and add it to the
+/// creator's tip total. Returns the fee taken.
+pub fn pay(env: &Env, payer: &Address, creator: &Address, tip: i128) -> i128 {
+    let fee = tip * fee_bps(env) as i128 / 10_000;
+    let token_client = token_client(env);
+    if fee > 0 {
+        token_client.transfer(payer, &fee_recipient(env), &fee);
+    }
+    if tip - fee > 0 {
+        token_client.transfer(payer, creator, &(tip - fee));
+    }
+    env.storage().instance().set(
+        &DataKey::TipTotal(creator.clone()),
+        &(total(env, creator) + tip),
+    );
+    fee
+}
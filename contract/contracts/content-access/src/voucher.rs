@@ -0,0 +1,66 @@
+//! Creator-signed vouchers redeemable for content access.
+//!
+//! A creator registers an ed25519 public key, then signs `Voucher`s off chain
+//! (promo codes, press copies, refunds). The signed message is the XDR of
+//! `(contract_address, voucher)`, so a voucher cannot be replayed against
+//! another deployment. Redemptions are counted per (creator, nonce).
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+/// Off-chain grant of access to one content item, signed by the creator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    pub creator: Address,
+    pub content_id: u64,
+    /// Price charged on redemption, overriding the content price; 0 for free access.
+    pub price: i128,
+    /// Ledgers of access granted from redemption, or `None` for lifetime access.
+    pub duration_ledgers: Option<u64>,
+    /// Ledger sequence from which the voucher can no longer be redeemed (exclusive).
+    pub expires_at: u64,
+    /// Creator-chosen identifier; vouchers sharing a nonce share a use count.
+    pub nonce: u64,
+    /// Redemptions allowed for this nonce; 1 for a single-use code.
+    pub max_uses: u32,
+    /// Only this buyer may redeem the voucher, when set.
+    pub buyer: Option<Address>,
+}
+
+/// Bytes the creator signs for `voucher`.
+pub fn message(env: &Env, voucher: &Voucher) -> Bytes {
+    (env.current_contract_address(), voucher.clone()).to_xdr(env)
+}
+
+/// Verify `signature` against the creator's registered voucher key.
+///
+/// An invalid signature traps in the host's ed25519 verification.
+pub fn require_valid_signature(env: &Env, voucher: &Voucher, signature: &BytesN<64>) {
+    let public_key: BytesN<32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VoucherKey(voucher.creator.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, Error::VoucherKeyNotSet));
+    env.crypto()
+        .ed25519_verify(&public_key, &message(env, voucher), signature);
+}
+
+pub fn uses(env: &Env, creator: &Address, nonce: u64) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::VoucherUses(creator.clone(), nonce))
+        .unwrap_or(0)
+}
+
+/// Count one redemption of `voucher`'s nonce, rejecting it once `max_uses` is reached.
+pub fn consume(env: &Env, voucher: &Voucher) {
+    let used = uses(env, &voucher.creator, voucher.nonce);
+    if used >= voucher.max_uses {
+        panic_with_error!(env, Error::VoucherExhausted);
+    }
+    env.storage().instance().set(
+        &DataKey::VoucherUses(voucher.creator.clone(), voucher.nonce),
+        &(used + 1),
+    );
+}
@@ -29,6 +29,24 @@ pub mod subscription {
     pub const INVALID_PRICE: u32 = 9;
     /// Plan ID does not exist; never created or out of range.
     pub const PLAN_NOT_FOUND: u32 = 10;
+    /// Plan amount not positive or interval zero.
+    pub const INVALID_PLAN_PARAMS: u32 = 11;
+    /// Keeper reward negative or keeper pool funding non-positive.
+    pub const INVALID_AMOUNT: u32 = 12;
+    pub const SELF_REFERRAL: u32 = 13;
+    pub const CIRCULAR_REFERRAL: u32 = 14;
+    pub const ORACLE_NOT_CONFIGURED: u32 = 15;
+    pub const ORACLE_PRICE_UNAVAILABLE: u32 = 16;
+    pub const STALE_PRICE: u32 = 17;
+    /// Oracle-converted charge exceeds the fan-signed `max_amount`.
+    pub const SLIPPAGE_EXCEEDED: u32 = 18;
+    pub const MIGRATION_IN_PROGRESS: u32 = 19;
+    pub const NOT_IN_MIGRATION: u32 = 20;
+    pub const MIGRATION_FINALIZED: u32 = 21;
+    pub const CHECKSUM_MISMATCH: u32 = 22;
+    pub const BATCH_TOO_LARGE: u32 = 23;
+    /// Reference-currency plan charged without a fan-signed `max_amount`.
+    pub const MAX_AMOUNT_REQUIRED: u32 = 24;
 }
 
 /// Error codes for the **content-access** contract.
@@ -42,6 +60,65 @@ pub mod content_access {
     pub const INVALID_PRICE: u32 = 7;
     pub const PRICE_EXCEEDS_MAX: u32 = 8;
     pub const INVALID_MAX_PRICE: u32 = 9;
+    pub const PAUSED: u32 = 10;
+    /// Access option with a zero-ledger rental duration.
+    pub const INVALID_EXPIRY: u32 = 11;
+    /// Catalog entry is deactivated; new purchases are refused.
+    pub const CONTENT_INACTIVE: u32 = 12;
+    pub const CONTENT_ALREADY_REGISTERED: u32 = 13;
+    pub const CONTENT_NOT_FOUND: u32 = 14;
+    pub const ACCESS_OPTION_NOT_FOUND: u32 = 15;
+    pub const TOO_MANY_ACCESS_OPTIONS: u32 = 16;
+    pub const BUNDLE_NOT_FOUND: u32 = 17;
+    /// Bundle members empty, duplicated, or over the size cap.
+    pub const INVALID_BUNDLE: u32 = 18;
+    /// Fee recipient is the Stellar null account.
+    pub const INVALID_FEE_RECIPIENT: u32 = 19;
+    /// Fee above 10_000 basis points.
+    pub const INVALID_FEE_BPS: u32 = 20;
+    pub const VOUCHER_KEY_NOT_SET: u32 = 21;
+    pub const VOUCHER_EXPIRED: u32 = 22;
+    /// Voucher nonce already redeemed `max_uses` times.
+    pub const VOUCHER_EXHAUSTED: u32 = 23;
+    pub const VOUCHER_NOT_FOR_BUYER: u32 = 24;
+    pub const NOT_REFUNDABLE: u32 = 25;
+    pub const REFUND_WINDOW_CLOSED: u32 = 26;
+    /// Refunder is neither the content creator nor the admin.
+    pub const NOT_REFUNDER: u32 = 27;
+    /// Gift recipient already holds valid access.
+    pub const ALREADY_HAS_ACCESS: u32 = 28;
+    /// Content suspended or removed by admin moderation.
+    pub const CONTENT_MODERATED: u32 = 29;
+    /// `unlock_many` cart empty, oversized, or with duplicate items.
+    pub const INVALID_CART: u32 = 30;
+    /// Pre-order content before its release ledger.
+    pub const NOT_YET_RELEASED: u32 = 31;
+    pub const ALREADY_RELEASED: u32 = 32;
+    pub const INVALID_RELEASE_LEDGER: u32 = 33;
+    pub const DELEGATION_LIMIT_REACHED: u32 = 34;
+    pub const DELEGATION_COOLDOWN: u32 = 35;
+    pub const ALREADY_DELEGATED: u32 = 36;
+    pub const SOLD_OUT: u32 = 37;
+    pub const INVALID_SUPPLY_CAP: u32 = 38;
+    pub const RESALE_DISABLED: u32 = 39;
+    pub const NOT_RESELLABLE: u32 = 40;
+    pub const LISTING_NOT_FOUND: u32 = 41;
+    pub const PRICE_BELOW_MIN: u32 = 42;
+    pub const PRICE_EXCEEDS_CATEGORY_MAX: u32 = 43;
+    pub const PRICE_BELOW_CATEGORY_MIN: u32 = 44;
+    pub const PRICE_EXCEEDS_TRUST_MAX: u32 = 45;
+    pub const PRICE_BELOW_TRUST_MIN: u32 = 46;
+    pub const INVALID_PRICE_RULE: u32 = 47;
+    pub const AMOUNT_BELOW_PRICE: u32 = 48;
+    pub const TIPS_DISABLED: u32 = 49;
+    pub const INVALID_SALE: u32 = 50;
+    pub const CREATOR_NOT_REGISTERED: u32 = 51;
+    // Codes 52+ are defined in `content_access::AttestationError` and `PreOrderError`.
+    pub const ATTESTOR_NOT_APPROVED: u32 = 52;
+    pub const ATTESTATION_EXPIRED: u32 = 53;
+    pub const ATTESTATION_REQUIRED: u32 = 54;
+    pub const ESCROW_PENDING: u32 = 55;
+    pub const REFUNDS_PENDING: u32 = 56;
 }
 
 /// Error codes for the **content-likes** contract.
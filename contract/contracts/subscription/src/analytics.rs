@@ -0,0 +1,159 @@
+//! Per-creator and per-plan subscription counters.
+//!
+//! Every state transition (subscribe, extend, cancel, sweep) funnels through
+//! the helpers here so the stored counters never drift from each other.
+
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+/// Revenue is bucketed into 30-day periods of ledgers (17 280 ledgers/day).
+pub const REVENUE_PERIOD_LEDGERS: u32 = 30 * 17_280;
+
+/// Subscription counters for a creator or a single plan.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionStats {
+    /// Subscribers that have not been cancelled or swept as expired.
+    pub active: u32,
+    /// Paid `subscribe` / `create_subscription` calls, including re-subscribes.
+    pub new_subscriptions: u32,
+    /// Cancellations keyed by the `reason` code passed to `cancel`.
+    pub cancellations: Map<u32, u32>,
+    /// Records transitioned to `Expired` by `sweep_expired`.
+    pub expirations: u32,
+    /// Lifetime revenue credited to the creator, net of protocol fees, keyed
+    /// by the asset it was paid in.
+    pub revenue: Map<Address, i128>,
+}
+
+/// Index of the revenue period containing `ledger`.
+pub fn period_of(ledger: u32) -> u32 {
+    ledger / REVENUE_PERIOD_LEDGERS
+}
+
+pub fn creator_stats(env: &Env, creator: &Address) -> SubscriptionStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorStats(creator.clone()))
+        .unwrap_or_else(|| SubscriptionStats {
+            // Seed from the legacy counter so upgraded deployments keep their count.
+            active: env
+                .storage()
+                .instance()
+                .get(&DataKey::CreatorSubscriptionCount(creator.clone()))
+                .unwrap_or(0),
+            ..empty(env)
+        })
+}
+
+pub fn plan_stats(env: &Env, plan_id: u32) -> SubscriptionStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlanStats(plan_id))
+        .unwrap_or_else(|| empty(env))
+}
+
+fn empty(env: &Env) -> SubscriptionStats {
+    SubscriptionStats {
+        active: 0,
+        new_subscriptions: 0,
+        cancellations: Map::new(env),
+        expirations: 0,
+        revenue: Map::new(env),
+    }
+}
+
+/// Apply `f` to the creator's counters and, for plan-based records
+/// (`plan_id != 0`), to the plan's counters.
+pub fn update(env: &Env, creator: &Address, plan_id: u32, f: impl Fn(&mut SubscriptionStats)) {
+    update_creator(env, creator, &f);
+    update_plan(env, plan_id, &f);
+}
+
+fn update_creator(env: &Env, creator: &Address, f: impl Fn(&mut SubscriptionStats)) {
+    let mut stats = creator_stats(env, creator);
+    f(&mut stats);
+    env.storage().instance().set(
+        &DataKey::CreatorSubscriptionCount(creator.clone()),
+        &stats.active,
+    );
+    env.storage()
+        .instance()
+        .set(&DataKey::CreatorStats(creator.clone()), &stats);
+}
+
+fn update_plan(env: &Env, plan_id: u32, f: impl Fn(&mut SubscriptionStats)) {
+    if plan_id == 0 {
+        return;
+    }
+    let mut stats = plan_stats(env, plan_id);
+    f(&mut stats);
+    env.storage()
+        .instance()
+        .set(&DataKey::PlanStats(plan_id), &stats);
+}
+
+/// Count a paid subscription to `plan_id`.
+///
+/// `active_plan` is the plan the fan was already counted as active under, if
+/// any; re-subscribing moves the fan between plans without double counting.
+pub fn record_subscription(env: &Env, creator: &Address, plan_id: u32, active_plan: Option<u32>) {
+    update(env, creator, plan_id, |s| s.new_subscriptions += 1);
+    record_active(env, creator, plan_id, active_plan);
+}
+
+/// Count the fan as active under `plan_id`, moving them off `active_plan`.
+pub fn record_active(env: &Env, creator: &Address, plan_id: u32, active_plan: Option<u32>) {
+    if active_plan.is_none() {
+        update_creator(env, creator, |s| s.active += 1);
+    }
+    if active_plan != Some(plan_id) {
+        update_plan(env, plan_id, |s| s.active += 1);
+    }
+    if let Some(previous) = active_plan.filter(|p| *p != plan_id) {
+        update_plan(env, previous, |s| s.active = s.active.saturating_sub(1));
+    }
+}
+
+/// Credit `amount` of creator revenue, paid in `asset`, to the current period.
+pub fn record_revenue(env: &Env, creator: &Address, plan_id: u32, asset: &Address, amount: i128) {
+    update(env, creator, plan_id, |s| {
+        let total = s.revenue.get(asset.clone()).unwrap_or(0);
+        s.revenue.set(asset.clone(), total + amount);
+    });
+
+    let period = period_of(env.ledger().sequence());
+    let key = DataKey::CreatorRevenue(creator.clone(), asset.clone(), period);
+    let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &(current + amount));
+    if plan_id != 0 {
+        let key = DataKey::PlanRevenue(plan_id, period);
+        let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(current + amount));
+    }
+}
+
+/// Count a cancellation under `reason` and drop the record from `active`
+/// when it was still being counted.
+pub fn record_cancellation(
+    env: &Env,
+    creator: &Address,
+    plan_id: u32,
+    reason: u32,
+    was_active: bool,
+) {
+    update(env, creator, plan_id, |s| {
+        if was_active {
+            s.active = s.active.saturating_sub(1);
+        }
+        let n = s.cancellations.get(reason).unwrap_or(0);
+        s.cancellations.set(reason, n + 1);
+    });
+}
+
+pub fn record_expiration(env: &Env, creator: &Address, plan_id: u32) {
+    update(env, creator, plan_id, |s| {
+        s.active = s.active.saturating_sub(1);
+        s.expirations += 1;
+    });
+}
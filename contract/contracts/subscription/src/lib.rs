@@ -1,9 +1,25 @@
 #![no_std]
+mod analytics;
+mod migration;
+mod oracle;
+mod referral;
+mod subscribers;
+
+pub use analytics::SubscriptionStats;
+pub use migration::{MigrationEntry, MigrationState};
+use myfans_lib::SubscriptionStatus;
+pub use oracle::{OracleConfig, PriceData, PriceOracle, PriceOracleClient};
+pub use referral::ReferralTotals;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, Env,
-    String, Symbol,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, BytesN,
+    Env, String, Symbol, Vec,
 };
 
+/// Upper bound on records examined by a single `sweep_expired` call.
+const MAX_SWEEP_LIMIT: u32 = 50;
+/// Upper bound on fans returned by a single `creator_subscribers` page.
+const MAX_PAGE_LIMIT: u32 = 100;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Plan {
@@ -37,6 +53,46 @@ pub enum DataKey {
     Token,
     Price,
     Paused,
+    /// Fan at a position in a creator's sweep index (persistent):
+    /// (creator, index) -> Address
+    CreatorSubscriber(Address, u32),
+    /// Length of a creator's sweep index (persistent): creator -> u32
+    CreatorSubscriberCount(Address),
+    /// Position of a tracked fan in the creator's sweep index (persistent);
+    /// present only while the fan is tracked: (creator, fan) -> u32
+    IsCreatorSubscriber(Address, Address),
+    /// Index into the creator's sweep index where the next sweep resumes.
+    SweepCursor(Address),
+    /// Lifecycle marker for (fan, creator); only written once a record is swept.
+    SubscriptionState(Address, Address),
+    /// Bounty paid to the caller of `sweep_expired` per record expired.
+    KeeperReward,
+    /// Admin-funded balance (in the default token) that keeper bounties draw from.
+    KeeperPool,
+    /// Aggregate counters per creator: creator -> SubscriptionStats
+    CreatorStats(Address),
+    /// Aggregate counters per plan: plan_id -> SubscriptionStats
+    PlanStats(u32),
+    /// Net creator revenue per asset per 30-day period: (creator, asset, period) -> i128
+    CreatorRevenue(Address, Address, u32),
+    /// Net creator revenue per plan per 30-day period: (plan_id, period) -> i128
+    PlanRevenue(u32, u32),
+    /// Share of the protocol fee paid to referrers, in basis points of the fee.
+    ReferralBps,
+    /// First-touch referrer of a fan: fan -> referrer
+    ReferredBy(Address),
+    /// Per-referrer payout totals: referrer -> ReferralTotals
+    ReferralTotals(Address),
+    /// Set once a fan has held any subscription to a creator: (fan, creator) -> bool
+    HasSubscribed(Address, Address),
+    /// Price oracle used to convert reference-currency plans: OracleConfig
+    OracleConfig,
+    /// Reference currency of a plan priced off-asset: plan_id -> Symbol
+    PlanCurrency(u32),
+    /// Migration window progress: MigrationState
+    Migration,
+    /// Creator-assigned tier of a plan: plan_id -> u32 (0 when unset)
+    PlanTier(u32),
 }
 
 impl DataKey {
@@ -70,6 +126,19 @@ impl DataKey {
 /// | 9 | `InvalidPrice` |
 /// | 10 | `PlanNotFound` |
 /// | 11 | `InvalidPlanParams` |
+/// | 12 | `InvalidAmount` |
+/// | 13 | `SelfReferral` |
+/// | 14 | `CircularReferral` |
+/// | 15 | `OracleNotConfigured` |
+/// | 16 | `OraclePriceUnavailable` |
+/// | 17 | `StalePrice` |
+/// | 18 | `SlippageExceeded` |
+/// | 19 | `MigrationInProgress` |
+/// | 20 | `NotInMigration` |
+/// | 21 | `MigrationFinalized` |
+/// | 22 | `ChecksumMismatch` |
+/// | 23 | `BatchTooLarge` |
+/// | 24 | `MaxAmountRequired` |
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Error {
@@ -95,6 +164,32 @@ pub enum Error {
     PlanNotFound = 10,
     /// Code 11 – plan `amount` must be strictly positive and `interval_days` non-zero.
     InvalidPlanParams = 11,
+    /// Code 12 – keeper reward must be non-negative and pool funding strictly positive.
+    InvalidAmount = 12,
+    /// Code 13 – a fan cannot refer themselves.
+    SelfReferral = 13,
+    /// Code 14 – the referrer was (directly or transitively) referred by the fan.
+    CircularReferral = 14,
+    /// Code 15 – plan is priced in a reference currency but no oracle is set.
+    OracleNotConfigured = 15,
+    /// Code 16 – oracle returned no price (or a non-positive one) for the plan.
+    OraclePriceUnavailable = 16,
+    /// Code 17 – oracle price is older than the configured staleness bound.
+    StalePrice = 17,
+    /// Code 18 – converted charge exceeds the fan-signed `max_amount`.
+    SlippageExceeded = 18,
+    /// Code 19 – a migration window is open; normal traffic is blocked.
+    MigrationInProgress = 19,
+    /// Code 20 – imports require an open migration window.
+    NotInMigration = 20,
+    /// Code 21 – migration was finalized; imports are permanently locked.
+    MigrationFinalized = 21,
+    /// Code 22 – running import checksum does not match the supplied value.
+    ChecksumMismatch = 22,
+    /// Code 23 – import batch is empty or exceeds the per-call limit.
+    BatchTooLarge = 23,
+    /// Code 24 – reference-currency plans need a fan-signed `max_amount`.
+    MaxAmountRequired = 24,
 }
 
 /// Stellar "null" account (GAAA...WHF) — not a valid fee recipient.
@@ -124,6 +219,37 @@ fn require_valid_token_address(env: &Env, token: &Address) {
     }
 }
 
+fn require_admin(env: &Env) -> Address {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotInitialized));
+    admin.require_auth();
+    admin
+}
+
+/// Track `fan` in the creator's sweep index and clear any prior expired marker.
+///
+/// Returns `true` if the fan was already tracked (counted as active).
+fn add_creator_subscriber(env: &Env, fan: &Address, creator: &Address) -> bool {
+    let tracked = subscribers::add(env, creator, fan);
+    env.storage()
+        .instance()
+        .remove(&DataKey::SubscriptionState(fan.clone(), creator.clone()));
+    tracked
+}
+
+fn is_marked_expired(env: &Env, fan: &Address, creator: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get::<DataKey, SubscriptionStatus>(&DataKey::SubscriptionState(
+            fan.clone(),
+            creator.clone(),
+        ))
+        == Some(SubscriptionStatus::Expired)
+}
+
 #[contract]
 pub struct MyfansContract;
 
@@ -187,6 +313,7 @@ impl MyfansContract {
         if paused {
             panic_with_error!(&env, Error::Paused);
         }
+        migration::require_not_migrating(&env);
         if amount <= 0 || interval_days == 0 {
             panic_with_error!(&env, Error::InvalidPlanParams);
         }
@@ -211,7 +338,54 @@ impl MyfansContract {
         plan_id
     }
 
-    pub fn subscribe(env: Env, fan: Address, plan_id: u32, token: Address) {
+    /// Create a plan priced in a reference currency (e.g. `USD`).
+    ///
+    /// `amount` is expressed in the reference currency with 7 decimals
+    /// (`5_0000000` = 5.00). Each charge converts it into `asset` using the
+    /// configured price oracle; see [`Self::set_price_oracle`].
+    ///
+    /// Event: `plan_created` — topics: `(name, creator)` data: `plan_id`
+    pub fn create_plan_in_currency(
+        env: Env,
+        creator: Address,
+        asset: Address,
+        currency: Symbol,
+        amount: i128,
+        interval_days: u32,
+    ) -> u32 {
+        let plan_id = Self::create_plan(env.clone(), creator, asset, amount, interval_days);
+        env.storage()
+            .instance()
+            .set(&DataKey::PlanCurrency(plan_id), &currency);
+        plan_id
+    }
+
+    /// Subscribe `fan` to `plan_id`, paying the plan amount in `token`.
+    ///
+    /// When `referrer` is set and this is the fan's first subscription to the
+    /// plan's creator, the configured referral bps of the protocol fee is paid
+    /// to the referrer instead of the fee recipient. The creator's share is
+    /// never reduced.
+    ///
+    /// For reference-currency plans the charge is converted at the oracle's
+    /// latest price; `max_amount` bounds what the fan is willing to pay.
+    ///
+    /// # Errors
+    /// * [`Error::SelfReferral`] – `referrer` is `fan`.
+    /// * [`Error::CircularReferral`] – `referrer` was referred by `fan`.
+    /// * [`Error::SlippageExceeded`] – the charge is above `max_amount`.
+    /// * [`Error::StalePrice`] / [`Error::OraclePriceUnavailable`] – no usable
+    ///   oracle price for a reference-currency plan.
+    ///
+    /// Event: `referral_paid` — topics: `(name, referrer, fan)` data: `(creator, reward)`
+    pub fn subscribe(
+        env: Env,
+        fan: Address,
+        plan_id: u32,
+        token: Address,
+        referrer: Option<Address>,
+        max_amount: Option<i128>,
+    ) {
         fan.require_auth();
         let paused: bool = env
             .storage()
@@ -221,6 +395,7 @@ impl MyfansContract {
         if paused {
             panic_with_error!(&env, Error::Paused);
         }
+        migration::require_not_migrating(&env);
 
         let plan: Plan = env
             .storage()
@@ -234,20 +409,50 @@ impl MyfansContract {
             panic_with_error!(&env, Error::InvalidTokenAddress);
         }
 
+        if let Some(referrer) = &referrer {
+            referral::require_valid_referrer(&env, &fan, referrer);
+        }
+        // Referrals only pay out on the fan's first subscription to this creator.
+        let referrer =
+            referrer.filter(|_| !referral::has_subscribed_before(&env, &fan, &plan.creator));
+
+        let amount = oracle::charge_amount(&env, plan_id, &plan);
+        oracle::require_within_bound(&env, plan_id, amount, max_amount);
+
         let fee_bps: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
-        let fee = (plan.amount * fee_bps as i128) / 10000;
-        let creator_amount = plan.amount - fee;
+        let fee = (amount * fee_bps as i128) / 10000;
+        let creator_amount = amount - fee;
+        let referral_reward = match referrer {
+            Some(_) => referral::reward_for(&env, fee),
+            None => 0,
+        };
+        let protocol_fee = fee - referral_reward;
 
         let token_client = token::Client::new(&env, &plan.asset);
         token_client.transfer(&fan, &plan.creator, &creator_amount);
-        if fee > 0 {
+        if protocol_fee > 0 {
             // Deferred read: only fetch fee_recipient when a fee is actually owed.
             let fee_recipient: Address = env
                 .storage()
                 .instance()
                 .get(&DataKey::FeeRecipient)
                 .unwrap();
-            token_client.transfer(&fan, &fee_recipient, &fee);
+            token_client.transfer(&fan, &fee_recipient, &protocol_fee);
+        }
+        if let Some(referrer) = &referrer {
+            if referral_reward > 0 {
+                token_client.transfer(&fan, referrer, &referral_reward);
+            }
+            referral::record(&env, &fan, referrer, &plan.asset, referral_reward);
+            // topics: (name, referrer, fan)  data: (creator, reward)
+            env.events().publish(
+                (
+                    Symbol::new(&env, "referral_paid"),
+                    referrer.clone(),
+                    fan.clone(),
+                ),
+                (plan.creator.clone(), referral_reward),
+            );
         }
 
         let expiry = env.ledger().sequence() + (plan.interval_days * 17280);
@@ -256,10 +461,18 @@ impl MyfansContract {
             plan_id,
             expiry: expiry as u64,
         };
-        env.storage().instance().set(
-            &DataKey::subscription(fan.clone(), plan.creator.clone()),
-            &sub,
+        let sub_key = DataKey::subscription(fan.clone(), plan.creator.clone());
+        let previous: Option<Subscription> = env.storage().instance().get(&sub_key);
+        env.storage().instance().set(&sub_key, &sub);
+        referral::mark_subscribed(&env, &fan, &plan.creator);
+        let tracked = add_creator_subscriber(&env, &fan, &plan.creator);
+        analytics::record_subscription(
+            &env,
+            &plan.creator,
+            plan_id,
+            previous.filter(|_| tracked).map(|p| p.plan_id),
         );
+        analytics::record_revenue(&env, &plan.creator, plan_id, &plan.asset, creator_amount);
         // topics: (name, fan, creator)  data: plan_id
         env.events().publish(
             (
@@ -290,12 +503,21 @@ impl MyfansContract {
         }
     }
 
+    /// Pay for `extra_ledgers` more on an active subscription.
+    ///
+    /// Charges one plan interval in the plan's asset; reference-currency plans
+    /// are converted at the oracle's latest price and bounded by the
+    /// fan-signed `max_amount`.
+    ///
+    /// # Errors
+    /// * [`Error::InvalidTokenAddress`] – `token` is not the plan's asset.
     pub fn extend_subscription(
         env: Env,
         fan: Address,
         creator: Address,
         extra_ledgers: u32,
         token: Address,
+        max_amount: Option<i128>,
     ) {
         fan.require_auth();
         let paused: bool = env
@@ -306,6 +528,7 @@ impl MyfansContract {
         if paused {
             panic_with_error!(&env, Error::Paused);
         }
+        migration::require_not_migrating(&env);
 
         let sub: Subscription = env
             .storage()
@@ -323,9 +546,17 @@ impl MyfansContract {
             .get(&DataKey::Plan(sub.plan_id))
             .unwrap_or_else(|| panic_with_error!(&env, Error::PlanNotFound));
 
+        // The charge is denominated in the plan's asset, so only that asset pays it.
+        if token != plan.asset {
+            panic_with_error!(&env, Error::InvalidTokenAddress);
+        }
+
+        let amount = oracle::charge_amount(&env, sub.plan_id, &plan);
+        oracle::require_within_bound(&env, sub.plan_id, amount, max_amount);
+
         let fee_bps: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
-        let fee = (plan.amount * fee_bps as i128) / 10000;
-        let creator_amount = plan.amount - fee;
+        let fee = (amount * fee_bps as i128) / 10000;
+        let creator_amount = amount - fee;
 
         let token_client = token::Client::new(&env, &token);
         token_client.transfer(&fan, &creator, &creator_amount);
@@ -339,6 +570,8 @@ impl MyfansContract {
             token_client.transfer(&fan, &fee_recipient, &fee);
         }
 
+        analytics::record_revenue(&env, &creator, sub.plan_id, &plan.asset, creator_amount);
+
         let new_expiry = sub.expiry + extra_ledgers as u64;
         let updated_sub = Subscription {
             fan: fan.clone(),
@@ -382,32 +615,30 @@ impl MyfansContract {
         if paused {
             panic_with_error!(&env, Error::Paused);
         }
+        migration::require_not_migrating(&env);
 
         // #1379: Guard — return SubscriptionNotFound when no subscription exists.
         // Prevents spurious cancel events and silent no-ops.
-        if !env
+        let sub: Subscription = env
             .storage()
             .instance()
-            .has(&DataKey::subscription(fan.clone(), creator.clone()))
-        {
-            panic_with_error!(&env, Error::SubscriptionNotFound);
-        }
+            .get(&DataKey::subscription(fan.clone(), creator.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::SubscriptionNotFound));
 
         env.storage()
             .instance()
             .remove(&DataKey::subscription(fan.clone(), creator.clone()));
 
-        // #1380: Decrement CreatorSubscriptionCount, floored at zero.
-        let current_count: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::CreatorSubscriptionCount(creator.clone()))
-            .unwrap_or(0);
-        let new_count = current_count.saturating_sub(1);
-        env.storage().instance().set(
-            &DataKey::CreatorSubscriptionCount(creator.clone()),
-            &new_count,
-        );
+        // A record already swept as expired was decremented by `sweep_expired`.
+        let was_active = !is_marked_expired(&env, &fan, &creator);
+        if was_active {
+            subscribers::remove(&env, &creator, &fan);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::SubscriptionState(fan.clone(), creator.clone()));
+        }
+        analytics::record_cancellation(&env, &creator, sub.plan_id, reason, was_active);
 
         // topics: (name, fan, creator)  data: (true, reason)
         env.events().publish(
@@ -426,6 +657,7 @@ impl MyfansContract {
         if paused {
             panic_with_error!(&env, Error::Paused);
         }
+        migration::require_not_migrating(&env);
 
         let token: Address = env
             .storage()
@@ -457,21 +689,18 @@ impl MyfansContract {
             expiry: expires_at_ledger as u64,
         };
 
-        env.storage()
-            .instance()
-            .set(&DataKey::subscription(fan.clone(), creator.clone()), &sub);
-
-        let mut current_count: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::CreatorSubscriptionCount(creator.clone()))
-            .unwrap_or(0);
-
-        current_count += 1;
-        env.storage().instance().set(
-            &DataKey::CreatorSubscriptionCount(creator.clone()),
-            &current_count,
+        let sub_key = DataKey::subscription(fan.clone(), creator.clone());
+        let previous: Option<Subscription> = env.storage().instance().get(&sub_key);
+        env.storage().instance().set(&sub_key, &sub);
+        referral::mark_subscribed(&env, &fan, &creator);
+        let tracked = add_creator_subscriber(&env, &fan, &creator);
+        analytics::record_subscription(
+            &env,
+            &creator,
+            0,
+            previous.filter(|_| tracked).map(|p| p.plan_id),
         );
+        analytics::record_revenue(&env, &creator, 0, &token, creator_amount);
 
         // topics: (name, fan, creator)  data: 0u32 (direct sub — no plan)
         env.events().publish(
@@ -565,6 +794,278 @@ impl MyfansContract {
             .publish((Symbol::new(&env, "fee_updated"),), (old, new_fee_bps));
     }
 
+    /// Configure the price oracle used for reference-currency plans (admin only).
+    ///
+    /// Prices older than `max_staleness` seconds are rejected at charge time.
+    /// Emits `oracle_updated` — topics: `(name, oracle)` data: `max_staleness`.
+    pub fn set_price_oracle(env: Env, oracle: Address, max_staleness: u64) {
+        require_admin(&env);
+        env.storage().instance().set(
+            &DataKey::OracleConfig,
+            &OracleConfig {
+                oracle: oracle.clone(),
+                max_staleness,
+            },
+        );
+        env.events()
+            .publish((Symbol::new(&env, "oracle_updated"), oracle), max_staleness);
+    }
+
+    /// Configured price oracle, if any.
+    pub fn price_oracle(env: Env) -> Option<OracleConfig> {
+        env.storage().instance().get(&DataKey::OracleConfig)
+    }
+
+    /// Reference currency of `plan_id`, or `None` for plans priced in their asset.
+    pub fn plan_currency(env: Env, plan_id: u32) -> Option<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlanCurrency(plan_id))
+    }
+
+    /// Amount of the plan asset one interval of `plan_id` costs right now.
+    ///
+    /// Clients should pass this (plus any slippage tolerance) as `max_amount`.
+    pub fn quote_plan(env: Env, plan_id: u32) -> i128 {
+        let plan: Plan = env
+            .storage()
+            .instance()
+            .get(&DataKey::Plan(plan_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PlanNotFound));
+        oracle::charge_amount(&env, plan_id, &plan)
+    }
+
+    /// Assign `tier` to `plan_id` (plan creator only).
+    ///
+    /// Tiers are creator-defined ranks; content gated on a minimum tier
+    /// accepts subscribers to any plan at or above it.
+    ///
+    /// Fails with [`Error::MigrationInProgress`] while an import is open.
+    ///
+    /// Event: `plan_tier_set` — topics: `(name, creator)` data: `(plan_id, tier)`
+    pub fn set_plan_tier(env: Env, plan_id: u32, tier: u32) {
+        migration::require_not_migrating(&env);
+        let plan: Plan = env
+            .storage()
+            .instance()
+            .get(&DataKey::Plan(plan_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PlanNotFound));
+        plan.creator.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::PlanTier(plan_id), &tier);
+        // topics: (name, creator)  data: (plan_id, tier)
+        env.events().publish(
+            (Symbol::new(&env, "plan_tier_set"), plan.creator),
+            (plan_id, tier),
+        );
+    }
+
+    /// Tier of `plan_id`; plans without an assigned tier are tier 0.
+    pub fn plan_tier(env: Env, plan_id: u32) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlanTier(plan_id))
+            .unwrap_or(0)
+    }
+
+    /// Tier of the plan behind `fan`'s active subscription to `creator`, or
+    /// `None` when [`Self::is_subscriber`] is false. Direct subscriptions
+    /// (`create_subscription`) are tier 0.
+    pub fn subscriber_tier(env: Env, fan: Address, creator: Address) -> Option<u32> {
+        if !Self::is_subscriber(env.clone(), fan.clone(), creator.clone()) {
+            return None;
+        }
+        let sub: Subscription = env
+            .storage()
+            .instance()
+            .get(&DataKey::subscription(fan, creator))?;
+        Some(Self::plan_tier(env, sub.plan_id))
+    }
+
+    /// Open the migration window (admin only).
+    ///
+    /// While open, every state-changing fan/creator entry point fails with
+    /// [`Error::MigrationInProgress`] and `import_subscriptions` is accepted.
+    /// Fails with [`Error::MigrationFinalized`] once `finalize_migration` ran.
+    pub fn begin_migration(env: Env) {
+        let admin = require_admin(&env);
+        let mut state = migration::state(&env);
+        if state.finalized {
+            panic_with_error!(&env, Error::MigrationFinalized);
+        }
+        state.active = true;
+        migration::set_state(&env, &state);
+        env.events()
+            .publish((Symbol::new(&env, "migration_started"),), admin);
+    }
+
+    /// Import a bounded batch of legacy plans and subscriptions (admin only).
+    ///
+    /// `checksum` must equal the running checksum after folding in every entry
+    /// of `batch`: `sha256(previous || xdr(entry))`, starting from 32 zero
+    /// bytes. A mismatch reverts the whole batch.
+    ///
+    /// Plans keep their original IDs and bump `PlanCount` as needed.
+    /// `PlanCurrency` and `PlanTier` entries restore a plan's reference
+    /// currency and tier; like subscriptions, they must follow their plan.
+    /// Subscriptions are tracked for sweeping and counted as active. A
+    /// subscription's plan must already exist (imported earlier or in the
+    /// same batch) and belong to its creator, or the batch fails with
+    /// [`Error::PlanNotFound`]; plan ID 0 marks a direct subscription.
+    ///
+    /// Event: `migration_batch` — data: `(imported_total, checksum)`
+    pub fn import_subscriptions(env: Env, batch: Vec<MigrationEntry>, checksum: BytesN<32>) {
+        require_admin(&env);
+        let mut state = migration::state(&env);
+        if state.finalized {
+            panic_with_error!(&env, Error::MigrationFinalized);
+        }
+        if !state.active {
+            panic_with_error!(&env, Error::NotInMigration);
+        }
+        if batch.is_empty() || batch.len() > migration::MAX_IMPORT_BATCH {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        for entry in batch.iter() {
+            state.checksum = migration::chain(&env, &state.checksum, &entry);
+            match entry {
+                MigrationEntry::Plan(plan_id, plan) => {
+                    if plan_id == 0 || plan.amount <= 0 || plan.interval_days == 0 {
+                        panic_with_error!(&env, Error::InvalidPlanParams);
+                    }
+                    env.storage().instance().set(&DataKey::Plan(plan_id), &plan);
+                    let count: u32 = env
+                        .storage()
+                        .instance()
+                        .get(&DataKey::PlanCount)
+                        .unwrap_or(0);
+                    if plan_id > count {
+                        env.storage().instance().set(&DataKey::PlanCount, &plan_id);
+                    }
+                }
+                MigrationEntry::Subscription(creator, sub) => {
+                    if sub.plan_id != 0 {
+                        let plan: Plan = env
+                            .storage()
+                            .instance()
+                            .get(&DataKey::Plan(sub.plan_id))
+                            .unwrap_or_else(|| panic_with_error!(&env, Error::PlanNotFound));
+                        if plan.creator != creator {
+                            panic_with_error!(&env, Error::PlanNotFound);
+                        }
+                    }
+                    let fan = sub.fan.clone();
+                    let sub_key = DataKey::subscription(fan.clone(), creator.clone());
+                    let previous: Option<Subscription> = env.storage().instance().get(&sub_key);
+                    env.storage().instance().set(&sub_key, &sub);
+                    referral::mark_subscribed(&env, &fan, &creator);
+                    let tracked = add_creator_subscriber(&env, &fan, &creator);
+                    analytics::record_active(
+                        &env,
+                        &creator,
+                        sub.plan_id,
+                        previous.filter(|_| tracked).map(|p| p.plan_id),
+                    );
+                }
+                MigrationEntry::PlanCurrency(plan_id, currency) => {
+                    if !env.storage().instance().has(&DataKey::Plan(plan_id)) {
+                        panic_with_error!(&env, Error::PlanNotFound);
+                    }
+                    env.storage()
+                        .instance()
+                        .set(&DataKey::PlanCurrency(plan_id), &currency);
+                }
+                MigrationEntry::PlanTier(plan_id, tier) => {
+                    if !env.storage().instance().has(&DataKey::Plan(plan_id)) {
+                        panic_with_error!(&env, Error::PlanNotFound);
+                    }
+                    env.storage()
+                        .instance()
+                        .set(&DataKey::PlanTier(plan_id), &tier);
+                }
+            }
+            state.imported += 1;
+        }
+
+        if state.checksum != checksum {
+            panic_with_error!(&env, Error::ChecksumMismatch);
+        }
+        migration::set_state(&env, &state);
+        env.events().publish(
+            (Symbol::new(&env, "migration_batch"),),
+            (state.imported, state.checksum),
+        );
+    }
+
+    /// Close the migration window and permanently lock imports (admin only).
+    ///
+    /// Event: `migration_finalized` — data: `(imported_total, checksum)`
+    pub fn finalize_migration(env: Env) {
+        require_admin(&env);
+        let mut state = migration::state(&env);
+        if state.finalized {
+            panic_with_error!(&env, Error::MigrationFinalized);
+        }
+        if !state.active {
+            panic_with_error!(&env, Error::NotInMigration);
+        }
+        state.active = false;
+        state.finalized = true;
+        migration::set_state(&env, &state);
+        env.events().publish(
+            (Symbol::new(&env, "migration_finalized"),),
+            (state.imported, state.checksum),
+        );
+    }
+
+    /// Current migration window state (imported count and running checksum).
+    pub fn migration_state(env: Env) -> MigrationState {
+        migration::state(&env)
+    }
+
+    /// Set the referrer's share of the protocol fee in basis points (admin only).
+    ///
+    /// `new_referral_bps` must be <= 10_000 (the whole fee leg). Emits
+    /// `referral_bps_updated` with data `(old_bps, new_bps)`.
+    pub fn set_referral_bps(env: Env, new_referral_bps: u32) {
+        require_admin(&env);
+        require_valid_fee_bps(&env, new_referral_bps);
+
+        let old: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReferralBps)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReferralBps, &new_referral_bps);
+
+        env.events().publish(
+            (Symbol::new(&env, "referral_bps_updated"),),
+            (old, new_referral_bps),
+        );
+    }
+
+    /// Referrer's share of the protocol fee in basis points.
+    pub fn referral_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReferralBps)
+            .unwrap_or(0)
+    }
+
+    /// Paid referral count and rewards earned by `referrer`, per asset.
+    pub fn referral_totals(env: Env, referrer: Address) -> ReferralTotals {
+        referral::totals(&env, &referrer)
+    }
+
+    /// The first referrer credited for `fan`, if any.
+    pub fn referred_by(env: Env, fan: Address) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ReferredBy(fan))
+    }
+
     /// Check if the contract is paused (view function)
     pub fn is_paused(env: Env) -> bool {
         env.storage()
@@ -609,6 +1110,277 @@ impl MyfansContract {
         (expiry_seq, expiry_unix)
     }
 
+    /// Set the bounty paid per record expired by `sweep_expired` (admin only).
+    ///
+    /// `reward` is denominated in the default token and must be non-negative;
+    /// `0` disables bounties without disabling sweeping.
+    ///
+    /// Emits `keeper_reward_updated` with data `(old_reward, new_reward)`.
+    pub fn set_keeper_reward(env: Env, reward: i128) {
+        require_admin(&env);
+        if reward < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let old: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::KeeperReward)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperReward, &reward);
+
+        env.events()
+            .publish((Symbol::new(&env, "keeper_reward_updated"),), (old, reward));
+    }
+
+    /// Top up the keeper bounty pool from the admin's balance (admin only).
+    ///
+    /// Transfers `amount` of the default token from the admin to this contract.
+    ///
+    /// Emits `keeper_pool_funded` — topics: `(name, admin)` data: `(amount, new_pool)`.
+    pub fn fund_keeper_pool(env: Env, amount: i128) {
+        let admin = require_admin(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::token_address())
+            .unwrap();
+        token::Client::new(&env, &token).transfer(&admin, &env.current_contract_address(), &amount);
+
+        let pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0);
+        let new_pool = pool + amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperPool, &new_pool);
+
+        env.events().publish(
+            (Symbol::new(&env, "keeper_pool_funded"), admin),
+            (amount, new_pool),
+        );
+    }
+
+    /// Mark lapsed subscriptions to `creator` as expired. Callable by anyone.
+    ///
+    /// Examines at most `limit` (capped at 50) tracked subscribers, resuming
+    /// where the previous sweep for this creator stopped. Each lapsed record is
+    /// marked [`SubscriptionStatus::Expired`], removed from the sweep index and
+    /// counted as an expiration in the creator and plan stats. The record itself is kept so
+    /// `get_expiry_unix` keeps reporting the lapsed expiry.
+    ///
+    /// `keeper` receives `keeper_reward` per expired record, paid from the
+    /// keeper pool and capped at what the pool holds.
+    ///
+    /// Returns the number of records expired.
+    ///
+    /// Events:
+    /// * `expired` — topics: `(name, fan, creator)` data: `plan_id` (per record)
+    /// * `swept` — topics: `(name, keeper, creator)` data: `(expired, bounty)`
+    pub fn sweep_expired(env: Env, keeper: Address, creator: Address, limit: u32) -> u32 {
+        keeper.require_auth();
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(&env, Error::Paused);
+        }
+        migration::require_not_migrating(&env);
+
+        let len = subscribers::count(&env, &creator);
+        let limit = core::cmp::min(limit, MAX_SWEEP_LIMIT);
+        if len == 0 || limit == 0 {
+            return 0;
+        }
+
+        let cursor_key = DataKey::SweepCursor(creator.clone());
+        let mut cursor: u32 = env.storage().instance().get(&cursor_key).unwrap_or(0);
+        if cursor >= len {
+            cursor = 0;
+        }
+        let current_seq = env.ledger().sequence() as u64;
+
+        // Only the `limit` entries from the cursor are visited; an expired
+        // fan's slot is refilled by the last fan, which is examined next.
+        let mut index = cursor;
+        let mut expired: u32 = 0;
+        for _ in 0..limit {
+            let Some(fan) = subscribers::at(&env, &creator, index) else {
+                break;
+            };
+            let sub: Option<Subscription> = env
+                .storage()
+                .instance()
+                .get(&DataKey::subscription(fan.clone(), creator.clone()));
+            match sub {
+                Some(sub) if sub.expiry < current_seq => {
+                    subscribers::remove(&env, &creator, &fan);
+                    env.storage().instance().set(
+                        &DataKey::SubscriptionState(fan.clone(), creator.clone()),
+                        &SubscriptionStatus::Expired,
+                    );
+                    analytics::record_expiration(&env, &creator, sub.plan_id);
+                    // topics: (name, fan, creator)  data: plan_id
+                    env.events().publish(
+                        (Symbol::new(&env, "expired"), fan, creator.clone()),
+                        sub.plan_id,
+                    );
+                    expired += 1;
+                }
+                // Record removed out-of-band: drop the stale index entry silently.
+                None => subscribers::remove(&env, &creator, &fan),
+                Some(_) => index += 1,
+            }
+        }
+        let next_cursor = if index >= subscribers::count(&env, &creator) {
+            0
+        } else {
+            index
+        };
+        env.storage().instance().set(&cursor_key, &next_cursor);
+
+        let mut bounty: i128 = 0;
+        if expired > 0 {
+            let reward: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::KeeperReward)
+                .unwrap_or(0);
+            let pool: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::KeeperPool)
+                .unwrap_or(0);
+            bounty = core::cmp::min(reward * expired as i128, pool);
+            if bounty > 0 {
+                let token: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::token_address())
+                    .unwrap();
+                token::Client::new(&env, &token).transfer(
+                    &env.current_contract_address(),
+                    &keeper,
+                    &bounty,
+                );
+                env.storage()
+                    .instance()
+                    .set(&DataKey::KeeperPool, &(pool - bounty));
+            }
+        }
+
+        // topics: (name, keeper, creator)  data: (expired, bounty)
+        env.events().publish(
+            (Symbol::new(&env, "swept"), keeper, creator),
+            (expired, bounty),
+        );
+        expired
+    }
+
+    /// Lifecycle status of the (fan, creator) subscription, or `None` if no
+    /// record exists.
+    ///
+    /// A lapsed record reports `Expired` whether or not it has been swept yet.
+    pub fn subscription_status(
+        env: Env,
+        fan: Address,
+        creator: Address,
+    ) -> Option<SubscriptionStatus> {
+        let sub: Subscription = env
+            .storage()
+            .instance()
+            .get(&DataKey::subscription(fan.clone(), creator.clone()))?;
+        if is_marked_expired(&env, &fan, &creator) || env.ledger().sequence() as u64 > sub.expiry {
+            Some(SubscriptionStatus::Expired)
+        } else {
+            Some(SubscriptionStatus::Active)
+        }
+    }
+
+    /// Paginated fans tracked for `creator`: subscribers that have not been
+    /// cancelled or swept as expired. A removed fan's slot is taken by the
+    /// last fan in the list.
+    ///
+    /// # Returns
+    /// (page of fans, next_cursor) — `next_cursor` is 0 when there is no next page
+    pub fn creator_subscribers(
+        env: Env,
+        creator: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<Address>, u32) {
+        subscribers::page(
+            &env,
+            &creator,
+            cursor,
+            core::cmp::min(limit, MAX_PAGE_LIMIT),
+        )
+    }
+
+    /// Number of subscribers to `creator` that have not been cancelled or swept.
+    pub fn creator_subscription_count(env: Env, creator: Address) -> u32 {
+        analytics::creator_stats(&env, &creator).active
+    }
+
+    /// Aggregate counters for `creator` across direct and plan-based subscriptions.
+    pub fn creator_stats(env: Env, creator: Address) -> SubscriptionStats {
+        analytics::creator_stats(&env, &creator)
+    }
+
+    /// Aggregate counters for a single plan.
+    pub fn plan_stats(env: Env, plan_id: u32) -> SubscriptionStats {
+        analytics::plan_stats(&env, plan_id)
+    }
+
+    /// Net creator revenue paid in `asset` and booked in revenue `period`.
+    ///
+    /// Periods are 30-day buckets of ledgers; see [`Self::revenue_period`].
+    pub fn creator_revenue(env: Env, creator: Address, asset: Address, period: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CreatorRevenue(creator, asset, period))
+            .unwrap_or(0)
+    }
+
+    /// Net creator revenue booked against `plan_id` in revenue `period`.
+    pub fn plan_revenue(env: Env, plan_id: u32, period: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlanRevenue(plan_id, period))
+            .unwrap_or(0)
+    }
+
+    /// Index of the current revenue period (`ledger / 518_400`).
+    pub fn revenue_period(env: Env) -> u32 {
+        analytics::period_of(env.ledger().sequence())
+    }
+
+    /// Current bounty paid per swept record.
+    pub fn keeper_reward(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperReward)
+            .unwrap_or(0)
+    }
+
+    /// Remaining balance of the keeper bounty pool.
+    pub fn keeper_pool(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperPool)
+            .unwrap_or(0)
+    }
+
     /// Health check: verifies the contract is reachable and the Soroban RPC
     /// node is connected.
     ///
@@ -0,0 +1,80 @@
+//! One-shot state import used when redeploying the subscription contract.
+//!
+//! The admin opens a migration window with `begin_migration`, replays legacy
+//! plans, their reference currencies and tiers, and subscriptions through
+//! `import_subscriptions` in bounded batches,
+//! and locks the contract against further imports with `finalize_migration`.
+//! Each batch carries the exporter's running checksum so a dropped, reordered
+//! or altered entry aborts the import instead of silently diverging.
+
+use crate::{DataKey, Error, Plan, Subscription};
+use soroban_sdk::{
+    contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol,
+};
+
+/// Upper bound on entries accepted by a single `import_subscriptions` call.
+pub const MAX_IMPORT_BATCH: u32 = 25;
+
+/// A single legacy record to import.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigrationEntry {
+    /// Plan record written under its original ID.
+    Plan(u32, Plan),
+    /// Subscription record for `(subscription.fan, creator)`.
+    Subscription(Address, Subscription),
+    /// Reference currency of an imported plan priced off-asset.
+    PlanCurrency(u32, Symbol),
+    /// Creator-assigned tier of an imported plan.
+    PlanTier(u32, u32),
+}
+
+/// Progress of the migration window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationState {
+    /// Normal traffic is blocked and imports are accepted.
+    pub active: bool,
+    /// Imports are permanently locked.
+    pub finalized: bool,
+    /// Entries imported so far.
+    pub imported: u32,
+    /// `sha256(previous || xdr(entry))` folded over every imported entry,
+    /// starting from 32 zero bytes.
+    pub checksum: BytesN<32>,
+}
+
+pub fn state(env: &Env) -> MigrationState {
+    env.storage()
+        .instance()
+        .get(&DataKey::Migration)
+        .unwrap_or_else(|| MigrationState {
+            active: false,
+            finalized: false,
+            imported: 0,
+            checksum: BytesN::from_array(env, &[0u8; 32]),
+        })
+}
+
+pub fn set_state(env: &Env, state: &MigrationState) {
+    env.storage().instance().set(&DataKey::Migration, state);
+}
+
+/// Block normal state-changing traffic while a migration window is open.
+pub fn require_not_migrating(env: &Env) {
+    if env
+        .storage()
+        .instance()
+        .get::<DataKey, MigrationState>(&DataKey::Migration)
+        .is_some_and(|s| s.active)
+    {
+        panic_with_error!(env, Error::MigrationInProgress);
+    }
+}
+
+/// Fold `entry` into the running checksum.
+pub fn chain(env: &Env, checksum: &BytesN<32>, entry: &MigrationEntry) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &checksum.to_array());
+    data.append(&entry.clone().to_xdr(env));
+    env.crypto().sha256(&data).to_bytes()
+}
@@ -0,0 +1,98 @@
+//! Reference-currency plan pricing via an external price oracle.
+//!
+//! Plans created with `create_plan_in_currency` keep their `Plan::amount` in
+//! the reference currency (7 decimals). At charge time the amount is converted
+//! into the plan asset using the configured oracle's latest price.
+
+use crate::{DataKey, Error, Plan};
+use soroban_sdk::{contractclient, contracttype, panic_with_error, token, Address, Env, Symbol};
+
+/// Fixed-point scale of reference-currency plan amounts (`5_0000000` = 5.00).
+pub const REFERENCE_DECIMALS: u32 = 7;
+
+/// Latest price reported by an oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    /// Price of one whole `asset` token in the quote currency, scaled by
+    /// `10^decimals()`.
+    pub price: i128,
+    /// Unix timestamp (seconds) at which the price was published.
+    pub timestamp: u64,
+}
+
+/// Admin-configured oracle and freshness bound.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    /// Maximum age, in seconds, of a price accepted at charge time.
+    pub max_staleness: u64,
+}
+
+/// Interface the subscription contract expects from a price oracle.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Latest price of `asset` quoted in `currency`, or `None` if unavailable.
+    fn lastprice(env: Env, asset: Address, currency: Symbol) -> Option<PriceData>;
+    /// Number of decimals used by `PriceData::price`.
+    fn decimals(env: Env) -> u32;
+}
+
+/// Amount of `plan.asset` owed for one billing interval.
+///
+/// Plans without a reference currency are charged `plan.amount` as-is.
+pub fn charge_amount(env: &Env, plan_id: u32, plan: &Plan) -> i128 {
+    let currency: Symbol = match env
+        .storage()
+        .instance()
+        .get(&DataKey::PlanCurrency(plan_id))
+    {
+        Some(currency) => currency,
+        None => return plan.amount,
+    };
+
+    let config: OracleConfig = env
+        .storage()
+        .instance()
+        .get(&DataKey::OracleConfig)
+        .unwrap_or_else(|| panic_with_error!(env, Error::OracleNotConfigured));
+    let oracle = PriceOracleClient::new(env, &config.oracle);
+    let data = oracle
+        .lastprice(&plan.asset, &currency)
+        .filter(|d| d.price > 0)
+        .unwrap_or_else(|| panic_with_error!(env, Error::OraclePriceUnavailable));
+
+    let now = env.ledger().timestamp();
+    if data.timestamp > now || now - data.timestamp > config.max_staleness {
+        panic_with_error!(env, Error::StalePrice);
+    }
+
+    let asset_decimals = token::Client::new(env, &plan.asset).decimals();
+    let numerator = plan.amount * 10i128.pow(asset_decimals) * 10i128.pow(oracle.decimals());
+    let denominator = data.price * 10i128.pow(REFERENCE_DECIMALS);
+    // Round up so conversion dust never shortchanges the creator.
+    (numerator + denominator - 1) / denominator
+}
+
+/// Reject a charge above the fan-signed `max_amount` bound.
+///
+/// The bound is optional for plans charged `plan.amount` as-is, but required
+/// for reference-currency plans, whose charge follows the oracle.
+pub fn require_within_bound(env: &Env, plan_id: u32, charge: i128, max_amount: Option<i128>) {
+    match max_amount {
+        Some(max_amount) if charge > max_amount => {
+            panic_with_error!(env, Error::SlippageExceeded)
+        }
+        Some(_) => {}
+        None => {
+            if env
+                .storage()
+                .instance()
+                .has(&DataKey::PlanCurrency(plan_id))
+            {
+                panic_with_error!(env, Error::MaxAmountRequired);
+            }
+        }
+    }
+}
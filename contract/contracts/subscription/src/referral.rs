@@ -0,0 +1,96 @@
+//! Referral attribution and payouts for first-time subscriptions.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map};
+
+/// How far up a referrer's own referral chain we look for cycles.
+const MAX_REFERRAL_DEPTH: u32 = 16;
+
+/// Running totals for a single referrer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralTotals {
+    /// First-time subscriptions that paid out a referral reward.
+    pub referrals: u32,
+    /// Sum of referral rewards received, per plan asset.
+    pub earned: Map<Address, i128>,
+}
+
+/// Totals recorded for `referrer`, empty if they never earned a reward.
+pub fn totals(env: &Env, referrer: &Address) -> ReferralTotals {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReferralTotals(referrer.clone()))
+        .unwrap_or_else(|| ReferralTotals {
+            referrals: 0,
+            earned: Map::new(env),
+        })
+}
+
+/// Reject self-referrals and referrers whose own referral chain leads back to `fan`.
+pub fn require_valid_referrer(env: &Env, fan: &Address, referrer: &Address) {
+    if referrer == fan {
+        panic_with_error!(env, Error::SelfReferral);
+    }
+    let mut current = referrer.clone();
+    for _ in 0..MAX_REFERRAL_DEPTH {
+        match env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::ReferredBy(current))
+        {
+            Some(upstream) if &upstream == fan => {
+                panic_with_error!(env, Error::CircularReferral)
+            }
+            Some(upstream) => current = upstream,
+            None => return,
+        }
+    }
+}
+
+/// Whether `fan` has ever held a subscription to `creator`.
+pub fn has_subscribed_before(env: &Env, fan: &Address, creator: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::HasSubscribed(fan.clone(), creator.clone()))
+        || env
+            .storage()
+            .instance()
+            .has(&DataKey::subscription(fan.clone(), creator.clone()))
+}
+
+pub fn mark_subscribed(env: &Env, fan: &Address, creator: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::HasSubscribed(fan.clone(), creator.clone()), &true);
+}
+
+/// Portion of the protocol `fee` owed to a referrer under the configured bps.
+pub fn reward_for(env: &Env, fee: i128) -> i128 {
+    let bps: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ReferralBps)
+        .unwrap_or(0);
+    (fee * bps as i128) / 10000
+}
+
+/// Attribute `fan` to `referrer` (first touch only) and, when a reward of
+/// `asset` was paid, add it to their totals.
+pub fn record(env: &Env, fan: &Address, referrer: &Address, asset: &Address, reward: i128) {
+    let referred_by = DataKey::ReferredBy(fan.clone());
+    if !env.storage().instance().has(&referred_by) {
+        env.storage().instance().set(&referred_by, referrer);
+    }
+    if reward <= 0 {
+        return;
+    }
+
+    let mut totals = totals(env, referrer);
+    totals.referrals += 1;
+    let earned = totals.earned.get(asset.clone()).unwrap_or(0);
+    totals.earned.set(asset.clone(), earned + reward);
+    env.storage()
+        .instance()
+        .set(&DataKey::ReferralTotals(referrer.clone()), &totals);
+}
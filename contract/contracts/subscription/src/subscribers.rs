@@ -0,0 +1,119 @@
+//! Per-creator index of fans holding a subscription record that has not yet
+//! been cancelled or swept as expired.
+//!
+//! The index lives in persistent storage with one key per fan, so it can grow
+//! without bloating the contract instance: membership is a single key lookup,
+//! and a page reads only the entries it returns. A removed fan's slot is
+//! filled by the list's last fan, keeping the list dense.
+
+use crate::DataKey;
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Once an index entry's remaining TTL drops below this many ledgers (about
+/// 30 days), it is refreshed back up to `SUBSCRIBER_TTL_EXTEND_TO`.
+const SUBSCRIBER_TTL_THRESHOLD: u32 = 518_400;
+/// About 180 days. See [`SUBSCRIBER_TTL_THRESHOLD`].
+const SUBSCRIBER_TTL_EXTEND_TO: u32 = 3_110_400;
+
+fn get<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage().persistent().extend_ttl(
+            key,
+            SUBSCRIBER_TTL_THRESHOLD,
+            SUBSCRIBER_TTL_EXTEND_TO,
+        );
+    }
+    value
+}
+
+fn set<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, SUBSCRIBER_TTL_THRESHOLD, SUBSCRIBER_TTL_EXTEND_TO);
+}
+
+/// Number of fans tracked for `creator`.
+pub fn count(env: &Env, creator: &Address) -> u32 {
+    get(env, &DataKey::CreatorSubscriberCount(creator.clone())).unwrap_or(0)
+}
+
+/// Fan tracked for `creator` at `index`, if any.
+pub fn at(env: &Env, creator: &Address, index: u32) -> Option<Address> {
+    get(env, &DataKey::CreatorSubscriber(creator.clone(), index))
+}
+
+/// Track `fan` for `creator`. Returns `true` if the fan was already tracked.
+pub fn add(env: &Env, creator: &Address, fan: &Address) -> bool {
+    let flag = DataKey::IsCreatorSubscriber(creator.clone(), fan.clone());
+    if get::<u32>(env, &flag).is_some() {
+        return true;
+    }
+    let index = count(env, creator);
+    set(
+        env,
+        &DataKey::CreatorSubscriber(creator.clone(), index),
+        fan,
+    );
+    set(env, &flag, &index);
+    set(
+        env,
+        &DataKey::CreatorSubscriberCount(creator.clone()),
+        &(index + 1),
+    );
+    false
+}
+
+/// Stop tracking `fan` for `creator`; a no-op if the fan is not tracked.
+pub fn remove(env: &Env, creator: &Address, fan: &Address) {
+    let flag = DataKey::IsCreatorSubscriber(creator.clone(), fan.clone());
+    let Some(index) = get::<u32>(env, &flag) else {
+        return;
+    };
+    env.storage().persistent().remove(&flag);
+
+    // Move the creator's last fan into the freed slot.
+    let last = count(env, creator) - 1;
+    if index != last {
+        if let Some(moved) = at(env, creator, last) {
+            set(
+                env,
+                &DataKey::CreatorSubscriber(creator.clone(), index),
+                &moved,
+            );
+            set(
+                env,
+                &DataKey::IsCreatorSubscriber(creator.clone(), moved),
+                &index,
+            );
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CreatorSubscriber(creator.clone(), last));
+    let count_key = DataKey::CreatorSubscriberCount(creator.clone());
+    if last == 0 {
+        env.storage().persistent().remove(&count_key);
+    } else {
+        set(env, &count_key, &last);
+    }
+}
+
+/// Page `[cursor, cursor + limit)` of `creator`'s tracked fans, with the
+/// cursor of the next page (0 at the end).
+pub fn page(env: &Env, creator: &Address, cursor: u32, limit: u32) -> (Vec<Address>, u32) {
+    let mut page = Vec::new(env);
+    let len = count(env, creator);
+    if cursor >= len || limit == 0 {
+        return (page, 0);
+    }
+    let end = core::cmp::min(cursor + limit, len);
+    for i in cursor..end {
+        if let Some(fan) = at(env, creator, i) {
+            page.push_back(fan);
+        }
+    }
+    let next_cursor = if end < len { end } else { 0 };
+    (page, next_cursor)
+}
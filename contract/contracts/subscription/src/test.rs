@@ -1,6 +1,7 @@
 use super::dummy_data::*;
 use super::*;
 use soroban_sdk::{
+    map,
     testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
     token, vec,
     xdr::{ScAddress, SorobanAuthorizationEntry},
@@ -55,7 +56,7 @@ fn test_subscribe_full_flow() {
     assert_eq!(plan_id, 1);
 
     // Subscribe calls token transfer, so it will deduct from fan
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     // Check balances
     // Fan paid 1000, should have 9000
@@ -219,7 +220,7 @@ fn test_subscribe_insufficient_balance_reverts() {
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
 
     // This should panic due to token transfer failure automatically mapped inside Soroban
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 }
 
 #[test]
@@ -237,7 +238,7 @@ fn test_platform_fee_zero() {
     token_admin.mint(&fan, &10000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     // Fee is 0%. Creator gets all 1000.
     assert_eq!(token.balance(&fee_recipient), 0);
@@ -255,7 +256,7 @@ fn test_cancel_subscription() {
 
     token_admin.mint(&fan, &10000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     assert!(client.is_subscriber(&fan, &creator));
 
@@ -311,7 +312,7 @@ fn test_create_subscription_insufficient_balance() {
     client.init(&admin, &500, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &500);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 }
 
 #[test]
@@ -326,7 +327,7 @@ fn test_extend_updates_expiry() {
         li.sequence_number = 1000;
     });
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     let initial_expiry = env.as_contract(&client.address, || {
         env.storage()
             .instance()
@@ -335,7 +336,7 @@ fn test_extend_updates_expiry() {
             .expiry
     });
 
-    client.extend_subscription(&fan, &creator, &7, &token.address);
+    client.extend_subscription(&fan, &creator, &7, &token.address, &None);
 
     let updated_expiry = env.as_contract(&client.address, || {
         env.storage()
@@ -356,7 +357,7 @@ fn test_create_subscription_no_fee() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &20000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     let initial_expiry = env.as_contract(&client.address, || {
         env.storage()
@@ -366,7 +367,7 @@ fn test_create_subscription_no_fee() {
             .expiry
     });
 
-    client.extend_subscription(&fan, &creator, &17280, &token.address);
+    client.extend_subscription(&fan, &creator, &17280, &token.address, &None);
 
     let updated_expiry = env.as_contract(&client.address, || {
         env.storage()
@@ -387,11 +388,11 @@ fn test_extend_requires_payment() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &20000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     assert_eq!(token.balance(&creator), 1000);
 
-    client.extend_subscription(&fan, &creator, &17280, &token.address);
+    client.extend_subscription(&fan, &creator, &17280, &token.address, &None);
 
     assert_eq!(token.balance(&creator), 2000);
     assert_eq!(token.balance(&fan), 18000);
@@ -409,7 +410,7 @@ fn test_extend_fails_if_expired() {
         li.sequence_number = 1000;
     });
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     env.as_contract(&client.address, || {
         let expired_sub = Subscription {
             fan: fan.clone(),
@@ -420,7 +421,7 @@ fn test_extend_fails_if_expired() {
             .instance()
             .set(&DataKey::Sub(fan.clone(), creator.clone()), &expired_sub);
     });
-    let result = client.try_extend_subscription(&fan, &creator, &17280, &token.address);
+    let result = client.try_extend_subscription(&fan, &creator, &17280, &token.address, &None);
     assert_eq!(
         result,
         Err(Ok(SorobanError::from_contract_error(
@@ -454,7 +455,7 @@ fn test_subscription_state_after_snapshot_restore() {
         &DUMMY_INTERVAL_DAYS,
     );
     assert_eq!(plan_id, 1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     let contract_id = client.address.clone();
     let expected_expiry = env.ledger().sequence() + (DUMMY_INTERVAL_DAYS * LEDGERS_PER_DAY);
@@ -643,7 +644,7 @@ fn test_extend_subscription_after_snapshot_restore() {
         &DUMMY_PLAN_AMOUNT,
         &DUMMY_INTERVAL_DAYS,
     );
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     let expiry_before: u64 = env.as_contract(&client.address, || {
         env.storage()
@@ -672,7 +673,7 @@ fn test_extend_subscription_after_snapshot_restore() {
     let client2 = MyfansContractClient::new(&env2, &contract_id2);
 
     const EXTRA: u32 = 7_000;
-    client2.extend_subscription(&fan2, &creator2, &EXTRA, &token_addr2);
+    client2.extend_subscription(&fan2, &creator2, &EXTRA, &token_addr2, &None);
 
     let expiry_after: u64 = env2.as_contract(&contract_id2, || {
         env2.storage()
@@ -743,7 +744,7 @@ fn test_subscribed_event_fields() {
     token_admin.mint(&fan, &5000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     let ev = find_event(&env, "subscribed").expect("subscribed event not emitted");
 
@@ -770,8 +771,8 @@ fn test_extended_event_fields() {
     token_admin.mint(&fan, &20000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
-    client.extend_subscription(&fan, &creator, &1000, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+    client.extend_subscription(&fan, &creator, &1000, &token.address, &None);
 
     // find the most recent subscribed-family event: extended
     let ev = find_event(&env, "extended").expect("extended event not emitted");
@@ -799,7 +800,7 @@ fn test_cancelled_event_fields() {
     token_admin.mint(&fan, &5000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.cancel(&fan, &creator, &1);
 
     let ev = find_event(&env, "cancelled").expect("cancelled event not emitted");
@@ -830,7 +831,7 @@ fn test_cancel_reason_code_zero() {
     token_admin.mint(&fan, &5000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.cancel(&fan, &creator, &0);
 
     let ev = find_event(&env, "cancelled").expect("cancelled event not emitted");
@@ -850,7 +851,7 @@ fn test_cancel_reason_code_other() {
     token_admin.mint(&fan, &5000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.cancel(&fan, &creator, &4);
 
     let ev = find_event(&env, "cancelled").expect("cancelled event not emitted");
@@ -870,7 +871,7 @@ fn test_cancel_event_topics_backward_compatible() {
     token_admin.mint(&fan, &5000);
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.cancel(&fan, &creator, &2);
 
     let ev = find_event(&env, "cancelled").expect("cancelled event not emitted");
@@ -928,7 +929,7 @@ fn test_get_expiry_unix_active_subscription() {
     });
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     // expiry_seq = 1000 + 30 * 17280 = 519400
     let expected_expiry_seq: u64 = 1000 + (30 * 17280);
@@ -958,7 +959,7 @@ fn test_get_expiry_unix_expired_subscription() {
     });
 
     let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     // Advance ledger well past expiry (1 day = 17280 ledgers)
     env.ledger().with_mut(|li| {
@@ -1037,7 +1038,7 @@ fn test_cancel_after_snapshot_restore() {
         &DUMMY_PLAN_AMOUNT,
         &DUMMY_INTERVAL_DAYS,
     );
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert!(client.is_subscriber(&fan, &creator));
 
     let contract_id = client.address.clone();
@@ -1123,7 +1124,7 @@ fn test_subscribe_fails_when_paused() {
     // create plan before pausing
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
     client.pause();
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 }
 
 #[test]
@@ -1135,15 +1136,38 @@ fn test_extend_subscription_fails_when_paused() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &50000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.pause();
-    let result = client.try_extend_subscription(&fan, &creator, &17280, &token.address);
+    let result = client.try_extend_subscription(&fan, &creator, &17280, &token.address, &None);
     assert_eq!(
         result,
         Err(Ok(SorobanError::from_contract_error(Error::Paused as u32)))
     );
 }
 
+#[test]
+fn test_extend_rejects_token_other_than_plan_asset() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    token_admin.mint(&fan, &50000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+
+    let cheap = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &cheap.address()).mint(&fan, &50000);
+    let result = client.try_extend_subscription(&fan, &creator, &17280, &cheap.address(), &None);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidTokenAddress as u32,
+        )))
+    );
+    assert_eq!(token.balance(&fan), 49000);
+}
+
 #[test]
 #[should_panic]
 fn test_cancel_fails_when_paused() {
@@ -1154,7 +1178,7 @@ fn test_cancel_fails_when_paused() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &50000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.pause();
     client.cancel(&fan, &creator, &0);
 }
@@ -1179,7 +1203,7 @@ fn test_views_available_when_paused() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &50000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
     client.pause();
 
@@ -1207,7 +1231,7 @@ fn test_mutations_succeed_after_unpause() {
 
     // mutations work again
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert!(client.is_subscriber(&fan, &creator));
 }
 
@@ -1276,7 +1300,7 @@ fn test_set_fee_recipient_admin_updates_storage_emits_event_and_routes_fees() {
     let fan = Address::generate(&env);
     token_admin.mint(&fan, &10000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert_eq!(token.balance(&new_recipient), 50);
     assert_eq!(token.balance(&fee_recipient), 0);
 }
@@ -1379,7 +1403,7 @@ fn test_set_fee_bps_admin_updates_storage_emits_event_and_fees() {
     let fan = Address::generate(&env);
     token_admin.mint(&fan, &10000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert_eq!(token.balance(&fee_recipient), 25);
     assert_eq!(token.balance(&creator), 975);
 }
@@ -1554,7 +1578,7 @@ fn test_init_stores_fee_bps_zero() {
     let fan2 = Address::generate(&env2);
     token_admin2.mint(&fan2, &5000);
     let plan_id = client2.create_plan(&creator2, &token2.address, &1000, &30);
-    client2.subscribe(&fan2, &plan_id, &token2.address);
+    client2.subscribe(&fan2, &plan_id, &token2.address, &None, &None);
     assert_eq!(
         token2.balance(&fee_recipient2),
         0,
@@ -1646,7 +1670,7 @@ fn test_set_fee_bps_updates_value() {
     let fan = Address::generate(&env);
     token_admin.mint(&fan, &5000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert_eq!(token.balance(&fee_recipient), 100, "10% fee = 100");
     assert_eq!(
         token.balance(&creator),
@@ -1667,7 +1691,7 @@ fn test_set_fee_recipient_updates_value() {
     let fan = Address::generate(&env);
     token_admin.mint(&fan, &5000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert_eq!(
         token.balance(&old_recipient),
         0,
@@ -1774,7 +1798,7 @@ fn test_subscribe_nonexistent_plan_returns_plan_not_found() {
     client.init(&admin, &500, &fee_recipient, &token.address, &1000);
     let fan = Address::generate(&env);
 
-    let result = client.try_subscribe(&fan, &9999u32, &token.address);
+    let result = client.try_subscribe(&fan, &9999u32, &token.address, &None, &None);
     assert_eq!(
         result,
         Err(Ok(SorobanError::from_contract_error(
@@ -1805,7 +1829,7 @@ fn test_subscribe_paused_returns_typed_error() {
     token_admin.mint(&fan, &50000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
     client.pause();
-    let result = client.try_subscribe(&fan, &plan_id, &token.address);
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert_eq!(
         result,
         Err(Ok(SorobanError::from_contract_error(Error::Paused as u32)))
@@ -1822,7 +1846,7 @@ fn test_cancel_paused_returns_typed_error() {
     client.init(&admin, &0, &fee_recipient, &token.address, &1000);
     token_admin.mint(&fan, &50000);
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
-    client.subscribe(&fan, &plan_id, &token.address);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
     client.pause();
     let result = client.try_cancel(&fan, &creator, &0);
     assert_eq!(
@@ -1895,3 +1919,1214 @@ fn test_create_plan_accepts_valid_params() {
     let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
     assert_eq!(plan_id, 1);
 }
+
+// ── Expiry sweeper & keeper rewards ──────────────────────────────────────────
+
+/// Initialise with a funded keeper pool paying `reward` per swept record.
+fn setup_sweeper(
+    reward: i128,
+    pool: i128,
+) -> (
+    Env,
+    MyfansContractClient<'static>,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    if pool > 0 {
+        token_admin.mint(&admin, &pool);
+        client.fund_keeper_pool(&pool);
+    }
+    client.set_keeper_reward(&reward);
+    (env, client, token, token_admin)
+}
+
+#[test]
+fn test_sweep_expired_marks_lapsed_and_pays_keeper() {
+    let (env, client, token, token_admin) = setup_sweeper(10, 1_000);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let lapsed = Address::generate(&env);
+    let active = Address::generate(&env);
+    token_admin.mint(&lapsed, &5000);
+    token_admin.mint(&active, &5000);
+
+    client.create_subscription(&lapsed, &creator, &100);
+    client.create_subscription(&active, &creator, &10_000);
+    assert_eq!(client.creator_subscription_count(&creator), 2);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    let swept = client.sweep_expired(&keeper, &creator, &10);
+
+    assert_eq!(swept, 1);
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+    assert_eq!(
+        client.subscription_status(&lapsed, &creator),
+        Some(SubscriptionStatus::Expired)
+    );
+    assert_eq!(
+        client.subscription_status(&active, &creator),
+        Some(SubscriptionStatus::Active)
+    );
+    assert_eq!(token.balance(&keeper), 10);
+    assert_eq!(client.keeper_pool(), 990);
+
+    let ev = find_event(&env, "expired").expect("expired event not emitted");
+    let t_fan: Address = ev.1.get(1).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(t_fan, lapsed);
+}
+
+#[test]
+fn test_sweep_expired_is_idempotent() {
+    let (env, client, token, token_admin) = setup_sweeper(10, 1_000);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &5000);
+    client.create_subscription(&fan, &creator, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(client.sweep_expired(&keeper, &creator, &10), 1);
+    assert_eq!(client.sweep_expired(&keeper, &creator, &10), 0);
+
+    assert_eq!(client.creator_subscription_count(&creator), 0);
+    assert_eq!(token.balance(&keeper), 10);
+}
+
+#[test]
+fn test_sweep_expired_respects_limit_and_resumes() {
+    let (env, client, _token, token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    for _ in 0..3 {
+        let fan = Address::generate(&env);
+        token_admin.mint(&fan, &5000);
+        client.create_subscription(&fan, &creator, &100);
+    }
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(client.sweep_expired(&keeper, &creator, &2), 2);
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+    assert_eq!(client.sweep_expired(&keeper, &creator, &2), 1);
+    assert_eq!(client.creator_subscription_count(&creator), 0);
+}
+
+#[test]
+fn test_sweep_expired_skips_active_window_and_wraps() {
+    let (env, client, _token, token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let long_fan = Address::generate(&env);
+    let short_fan = Address::generate(&env);
+    token_admin.mint(&long_fan, &5000);
+    token_admin.mint(&short_fan, &5000);
+    client.create_subscription(&long_fan, &creator, &10_000);
+    client.create_subscription(&short_fan, &creator, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    // First window only covers the still-active subscriber.
+    assert_eq!(client.sweep_expired(&keeper, &creator, &1), 0);
+    // Cursor advanced past it, so the next window reaches the lapsed one.
+    assert_eq!(client.sweep_expired(&keeper, &creator, &1), 1);
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+}
+
+#[test]
+fn test_creator_subscribers_pages_and_stays_dense() {
+    let (env, client, _token, token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let fans = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for fan in fans.iter() {
+        token_admin.mint(fan, &5000);
+        client.create_subscription(fan, &creator, &10_000);
+    }
+    // Renewing does not list a fan twice.
+    client.create_subscription(&fans[0], &creator, &10_000);
+
+    let (page, next) = client.creator_subscribers(&creator, &0, &2);
+    assert_eq!(page, vec![&env, fans[0].clone(), fans[1].clone()]);
+    assert_eq!(next, 2);
+    assert_eq!(
+        client.creator_subscribers(&creator, &next, &2),
+        (vec![&env, fans[2].clone()], 0)
+    );
+
+    // The last fan takes the cancelled fan's slot.
+    client.cancel(&fans[0], &creator, &0);
+    assert_eq!(
+        client.creator_subscribers(&creator, &0, &10),
+        (vec![&env, fans[2].clone(), fans[1].clone()], 0)
+    );
+}
+
+#[test]
+fn test_sweep_expired_bounty_capped_by_pool() {
+    let (env, client, token, token_admin) = setup_sweeper(50, 60);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    for _ in 0..2 {
+        let fan = Address::generate(&env);
+        token_admin.mint(&fan, &5000);
+        client.create_subscription(&fan, &creator, &100);
+    }
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(client.sweep_expired(&keeper, &creator, &10), 2);
+    assert_eq!(token.balance(&keeper), 60);
+    assert_eq!(client.keeper_pool(), 0);
+}
+
+#[test]
+fn test_cancel_after_sweep_does_not_double_decrement() {
+    let (env, client, _token, token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let other = Address::generate(&env);
+    token_admin.mint(&fan, &5000);
+    token_admin.mint(&other, &5000);
+    client.create_subscription(&fan, &creator, &100);
+    client.create_subscription(&other, &creator, &10_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.sweep_expired(&keeper, &creator, &10);
+    client.cancel(&fan, &creator, &0);
+
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+    assert_eq!(client.subscription_status(&fan, &creator), None);
+}
+
+#[test]
+fn test_resubscribe_after_sweep_is_active_again() {
+    let (env, client, _token, token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &5000);
+    client.create_subscription(&fan, &creator, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.sweep_expired(&keeper, &creator, &10);
+    client.create_subscription(&fan, &creator, &100);
+
+    assert_eq!(
+        client.subscription_status(&fan, &creator),
+        Some(SubscriptionStatus::Active)
+    );
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+}
+
+#[test]
+fn test_sweep_expired_fails_when_paused() {
+    let (env, client, _token, _token_admin) = setup_sweeper(0, 0);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    client.pause();
+
+    let result = client.try_sweep_expired(&keeper, &creator, &10);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(Error::Paused as u32)))
+    );
+}
+
+#[test]
+fn test_keeper_config_rejects_invalid_amounts() {
+    let (_env, client, _token, _token_admin) = setup_sweeper(0, 0);
+
+    assert_eq!(
+        client.try_set_keeper_reward(&-1),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidAmount as u32
+        )))
+    );
+    assert_eq!(
+        client.try_fund_keeper_pool(&0),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidAmount as u32
+        )))
+    );
+}
+
+// ── Creator / plan analytics ─────────────────────────────────────────────────
+
+#[test]
+fn test_subscribe_counts_plan_subscribers() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &500, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+
+    let stats = client.creator_stats(&creator);
+    assert_eq!(stats.active, 1);
+    assert_eq!(stats.new_subscriptions, 1);
+    assert_eq!(stats.revenue.get(token.address.clone()), Some(950));
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+
+    let plan = client.plan_stats(&plan_id);
+    assert_eq!(plan.active, 1);
+    assert_eq!(plan.revenue.get(token.address.clone()), Some(950));
+
+    let period = client.revenue_period();
+    assert_eq!(
+        client.creator_revenue(&creator, &token.address, &period),
+        950
+    );
+    assert_eq!(client.plan_revenue(&plan_id, &period), 950);
+}
+
+#[test]
+fn test_resubscribe_does_not_double_count_active() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+
+    let basic = client.create_plan(&creator, &token.address, &1000, &30);
+    let premium = client.create_plan(&creator, &token.address, &2000, &30);
+    client.subscribe(&fan, &basic, &token.address, &None, &None);
+    client.subscribe(&fan, &premium, &token.address, &None, &None);
+
+    let stats = client.creator_stats(&creator);
+    assert_eq!(stats.active, 1);
+    assert_eq!(stats.new_subscriptions, 2);
+    assert_eq!(stats.revenue.get(token.address.clone()), Some(3000));
+    assert_eq!(client.plan_stats(&basic).active, 0);
+    assert_eq!(client.plan_stats(&premium).active, 1);
+}
+
+#[test]
+fn test_cancel_counts_by_reason_code() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+    for reason in [
+        CANCEL_REASON_TOO_EXPENSIVE,
+        CANCEL_REASON_TOO_EXPENSIVE,
+        CANCEL_REASON_OTHER,
+    ] {
+        let fan = Address::generate(&env);
+        token_admin.mint(&fan, &5000);
+        client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+        client.cancel(&fan, &creator, &reason);
+    }
+
+    let stats = client.creator_stats(&creator);
+    assert_eq!(stats.active, 0);
+    assert_eq!(
+        stats.cancellations.get(CANCEL_REASON_TOO_EXPENSIVE),
+        Some(2)
+    );
+    assert_eq!(stats.cancellations.get(CANCEL_REASON_OTHER), Some(1));
+    assert_eq!(stats.cancellations.get(CANCEL_REASON_USER_INITIATED), None);
+    assert_eq!(
+        client
+            .plan_stats(&plan_id)
+            .cancellations
+            .get(CANCEL_REASON_TOO_EXPENSIVE),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_sweep_counts_expirations_per_plan() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &5000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &1);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number += LEDGERS_PER_DAY + 1);
+    client.sweep_expired(&keeper, &creator, &10);
+
+    let stats = client.creator_stats(&creator);
+    assert_eq!(stats.active, 0);
+    assert_eq!(stats.expirations, 1);
+    let plan = client.plan_stats(&plan_id);
+    assert_eq!(plan.active, 0);
+    assert_eq!(plan.expirations, 1);
+}
+
+#[test]
+fn test_revenue_is_bucketed_by_period() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+    let first = client.revenue_period();
+    env.ledger()
+        .with_mut(|li| li.sequence_number += 30 * LEDGERS_PER_DAY);
+    client.extend_subscription(&fan, &creator, &1000, &token.address, &None);
+    let second = client.revenue_period();
+
+    assert_eq!(second, first + 1);
+    assert_eq!(
+        client.creator_revenue(&creator, &token.address, &first),
+        1000
+    );
+    assert_eq!(
+        client.creator_revenue(&creator, &token.address, &second),
+        1000
+    );
+    assert_eq!(
+        client
+            .creator_stats(&creator)
+            .revenue
+            .get(token.address.clone()),
+        Some(2000)
+    );
+}
+
+#[test]
+fn test_revenue_is_kept_per_asset() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let other = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &other.address()).mint(&fan, &10_000);
+
+    let basic = client.create_plan(&creator, &token.address, &1000, &30);
+    let premium = client.create_plan(&creator, &other.address(), &7, &30);
+    client.subscribe(&fan, &basic, &token.address, &None, &None);
+    client.subscribe(&fan, &premium, &other.address(), &None, &None);
+
+    let stats = client.creator_stats(&creator);
+    assert_eq!(stats.revenue.len(), 2);
+    assert_eq!(stats.revenue.get(token.address.clone()), Some(1000));
+    assert_eq!(stats.revenue.get(other.address()), Some(7));
+    let period = client.revenue_period();
+    assert_eq!(
+        client.creator_revenue(&creator, &other.address(), &period),
+        7
+    );
+    assert_eq!(client.plan_revenue(&premium, &period), 7);
+}
+
+// ── Referral rewards ─────────────────────────────────────────────────────────
+
+/// 10% protocol fee with half of it routed to referrers.
+fn setup_referrals() -> (
+    Env,
+    MyfansContractClient<'static>,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+    Address,
+) {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &1000, &fee_recipient, &token.address, &1000);
+    client.set_referral_bps(&5000);
+    (env, client, token, token_admin, fee_recipient)
+}
+
+#[test]
+fn test_referral_paid_from_fee_leg_on_first_subscription() {
+    let (env, client, token, token_admin, fee_recipient) = setup_referrals();
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    client.subscribe(
+        &fan,
+        &plan_id,
+        &token.address,
+        &Some(referrer.clone()),
+        &None,
+    );
+
+    assert_eq!(
+        token.balance(&creator),
+        900,
+        "creator share must be untouched"
+    );
+    assert_eq!(token.balance(&referrer), 50);
+    assert_eq!(token.balance(&fee_recipient), 50);
+    assert_eq!(token.balance(&fan), 9000);
+    assert_eq!(
+        client.referral_totals(&referrer),
+        ReferralTotals {
+            referrals: 1,
+            earned: map![&env, (token.address.clone(), 50)],
+        }
+    );
+    assert_eq!(client.referred_by(&fan), Some(referrer));
+    assert!(find_event(&env, "referral_paid").is_some());
+}
+
+#[test]
+fn test_referral_not_paid_for_returning_subscriber() {
+    let (env, client, token, token_admin, fee_recipient) = setup_referrals();
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    client.subscribe(&fan, &plan_id, &token.address, &None, &None);
+    client.cancel(&fan, &creator, &CANCEL_REASON_OTHER);
+    client.subscribe(
+        &fan,
+        &plan_id,
+        &token.address,
+        &Some(referrer.clone()),
+        &None,
+    );
+
+    assert_eq!(token.balance(&referrer), 0);
+    assert_eq!(token.balance(&fee_recipient), 200);
+    assert_eq!(client.referral_totals(&referrer).referrals, 0);
+    assert!(client.referral_totals(&referrer).earned.is_empty());
+}
+
+#[test]
+fn test_referral_totals_keyed_by_asset_and_count_paid_only() {
+    let (env, client, token, token_admin, _) = setup_referrals();
+    let creator = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let other_admin = Address::generate(&env);
+    let other = token::Client::new(
+        &env,
+        &env.register_stellar_asset_contract_v2(other_admin.clone())
+            .address(),
+    );
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+    let other_plan = client.create_plan(&creator, &other.address, &4000, &30);
+
+    let fans = [Address::generate(&env), Address::generate(&env)];
+    token_admin.mint(&fans[0], &10_000);
+    token::StellarAssetClient::new(&env, &other.address).mint(&fans[1], &10_000);
+    client.subscribe(
+        &fans[0],
+        &plan_id,
+        &token.address,
+        &Some(referrer.clone()),
+        &None,
+    );
+    client.subscribe(
+        &fans[1],
+        &other_plan,
+        &other.address,
+        &Some(referrer.clone()),
+        &None,
+    );
+    assert_eq!(
+        client.referral_totals(&referrer),
+        ReferralTotals {
+            referrals: 2,
+            earned: map![
+                &env,
+                (token.address.clone(), 50),
+                (other.address.clone(), 200)
+            ],
+        }
+    );
+
+    // A referral that pays nothing is attributed but not counted.
+    client.set_referral_bps(&0);
+    let unpaid = Address::generate(&env);
+    token_admin.mint(&unpaid, &10_000);
+    client.subscribe(
+        &unpaid,
+        &plan_id,
+        &token.address,
+        &Some(referrer.clone()),
+        &None,
+    );
+    assert_eq!(client.referred_by(&unpaid), Some(referrer.clone()));
+    assert_eq!(client.referral_totals(&referrer).referrals, 2);
+}
+
+#[test]
+fn test_referral_rejects_self_referral() {
+    let (env, client, token, token_admin, _) = setup_referrals();
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &Some(fan.clone()), &None);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::SelfReferral as u32
+        )))
+    );
+}
+
+#[test]
+fn test_referral_rejects_circular_referral() {
+    let (env, client, token, token_admin, _) = setup_referrals();
+    let creator = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for fan in [&alice, &bob, &carol] {
+        token_admin.mint(fan, &10_000);
+    }
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    // alice refers bob, bob refers carol.
+    client.subscribe(&bob, &plan_id, &token.address, &Some(alice.clone()), &None);
+    client.subscribe(&carol, &plan_id, &token.address, &Some(bob.clone()), &None);
+
+    // Neither bob nor carol may now refer alice.
+    for referrer in [&bob, &carol] {
+        let result = client.try_subscribe(
+            &alice,
+            &plan_id,
+            &token.address,
+            &Some(referrer.clone()),
+            &None,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(SorobanError::from_contract_error(
+                Error::CircularReferral as u32
+            )))
+        );
+    }
+}
+
+#[test]
+fn test_set_referral_bps_rejects_over_10000() {
+    let (_env, client, _token, _token_admin, _) = setup_referrals();
+    assert_eq!(
+        client.try_set_referral_bps(&10_001),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::InvalidFeeBps as u32
+        )))
+    );
+    assert_eq!(client.referral_bps(), 5000);
+}
+
+// ── Reference-currency (oracle-priced) plans ─────────────────────────────────
+
+/// Mock price oracle: the test sets a single (price, timestamp) pair.
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "price"), &PriceData { price, timestamp });
+    }
+
+    pub fn clear_price(env: Env) {
+        env.storage().instance().remove(&Symbol::new(&env, "price"));
+    }
+}
+
+#[contractimpl]
+impl PriceOracle for MockOracle {
+    fn lastprice(env: Env, _asset: Address, _currency: Symbol) -> Option<PriceData> {
+        env.storage().instance().get(&Symbol::new(&env, "price"))
+    }
+
+    fn decimals(_env: Env) -> u32 {
+        14
+    }
+}
+
+const ORACLE_SCALE: i128 = 100_000_000_000_000;
+const NOW: u64 = 1_700_000_000;
+
+/// A USD plan costing 5.00 per 30 days, with an oracle pricing the token at $0.25.
+fn setup_usd_plan() -> (
+    Env,
+    MyfansContractClient<'static>,
+    token::Client<'static>,
+    MockOracleClient<'static>,
+    Address,
+    Address,
+    u32,
+) {
+    let (env, client, admin, token, token_admin) = setup_test();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+
+    let oracle_id = env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    oracle.set_price(&(ORACLE_SCALE / 4), &NOW);
+    client.set_price_oracle(&oracle_id, &300);
+
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &1_000_000_000);
+    let plan_id = client.create_plan_in_currency(
+        &creator,
+        &token.address,
+        &Symbol::new(&env, "USD"),
+        &50_000_000,
+        &30,
+    );
+    (env, client, token, oracle, creator, fan, plan_id)
+}
+
+#[test]
+fn test_currency_plan_charges_converted_amount() {
+    let (env, client, token, _oracle, creator, fan, plan_id) = setup_usd_plan();
+
+    // $5.00 at $0.25 per token = 20 tokens = 200_000_000 stroops.
+    assert_eq!(client.quote_plan(&plan_id), 200_000_000);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &Some(200_000_000));
+
+    assert_eq!(token.balance(&creator), 200_000_000);
+    assert!(client.is_subscriber(&fan, &creator));
+    assert_eq!(
+        client.plan_currency(&plan_id),
+        Some(Symbol::new(&env, "USD"))
+    );
+}
+
+#[test]
+fn test_currency_plan_renewal_uses_latest_price() {
+    let (_env, client, token, oracle, creator, fan, plan_id) = setup_usd_plan();
+    client.subscribe(&fan, &plan_id, &token.address, &None, &Some(200_000_000));
+
+    // Token doubles to $0.50: renewal costs 10 tokens.
+    oracle.set_price(&(ORACLE_SCALE / 2), &NOW);
+    client.extend_subscription(&fan, &creator, &1000, &token.address, &Some(100_000_000));
+
+    assert_eq!(token.balance(&creator), 300_000_000);
+}
+
+#[test]
+fn test_currency_plan_rejects_charge_above_max_amount() {
+    let (_env, client, token, oracle, _creator, fan, plan_id) = setup_usd_plan();
+    // Token halves to $0.125 after the fan signed for 20 tokens.
+    oracle.set_price(&(ORACLE_SCALE / 8), &NOW);
+
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &None, &Some(200_000_000));
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::SlippageExceeded as u32
+        )))
+    );
+}
+
+#[test]
+fn test_currency_plan_requires_max_amount() {
+    let (_env, client, token, oracle, creator, fan, plan_id) = setup_usd_plan();
+    // Token crashes to $0.0025: 5.00 USD would now cost 2000 tokens.
+    oracle.set_price(&(ORACLE_SCALE / 400), &NOW);
+
+    let required = Err(Ok(SorobanError::from_contract_error(
+        Error::MaxAmountRequired as u32,
+    )));
+    assert_eq!(
+        client.try_subscribe(&fan, &plan_id, &token.address, &None, &None),
+        required
+    );
+
+    oracle.set_price(&(ORACLE_SCALE / 4), &NOW);
+    client.subscribe(&fan, &plan_id, &token.address, &None, &Some(200_000_000));
+    oracle.set_price(&(ORACLE_SCALE / 400), &NOW);
+    assert_eq!(
+        client.try_extend_subscription(&fan, &creator, &1000, &token.address, &None),
+        required
+    );
+    assert_eq!(token.balance(&creator), 200_000_000);
+}
+
+#[test]
+fn test_currency_plan_rejects_stale_price() {
+    let (env, client, token, _oracle, _creator, fan, plan_id) = setup_usd_plan();
+    env.ledger().with_mut(|li| li.timestamp = NOW + 301);
+
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::StalePrice as u32
+        )))
+    );
+}
+
+#[test]
+fn test_currency_plan_rejects_missing_price() {
+    let (_env, client, token, oracle, _creator, fan, plan_id) = setup_usd_plan();
+    oracle.clear_price();
+
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::OraclePriceUnavailable as u32
+        )))
+    );
+}
+
+#[test]
+fn test_currency_plan_requires_configured_oracle() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &1_000_000_000);
+    let plan_id = client.create_plan_in_currency(
+        &creator,
+        &token.address,
+        &Symbol::new(&env, "USD"),
+        &50_000_000,
+        &30,
+    );
+
+    let result = client.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::OracleNotConfigured as u32
+        )))
+    );
+}
+
+#[test]
+fn test_asset_plan_ignores_oracle_and_honours_max_amount() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    assert_eq!(client.quote_plan(&plan_id), 1000);
+    assert_eq!(
+        client.try_subscribe(&fan, &plan_id, &token.address, &None, &Some(999)),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::SlippageExceeded as u32
+        )))
+    );
+    client.subscribe(&fan, &plan_id, &token.address, &None, &Some(1000));
+    assert_eq!(token.balance(&creator), 1000);
+}
+
+// ── Legacy import / migration ────────────────────────────────────────────────
+
+/// Running checksum the exporter would compute for `entries`.
+fn migration_checksum(
+    env: &Env,
+    start: soroban_sdk::BytesN<32>,
+    entries: &soroban_sdk::Vec<MigrationEntry>,
+) -> soroban_sdk::BytesN<32> {
+    use soroban_sdk::xdr::ToXdr;
+    let mut checksum = start;
+    for entry in entries.iter() {
+        let mut data = soroban_sdk::Bytes::from_array(env, &checksum.to_array());
+        data.append(&entry.to_xdr(env));
+        checksum = env.crypto().sha256(&data).to_bytes();
+    }
+    checksum
+}
+
+fn zero_checksum(env: &Env) -> soroban_sdk::BytesN<32> {
+    soroban_sdk::BytesN::from_array(env, &[0u8; 32])
+}
+
+#[test]
+fn test_import_subscriptions_seeds_plans_and_records() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let plan = Plan {
+        creator: creator.clone(),
+        asset: token.address.clone(),
+        amount: 1000,
+        interval_days: 30,
+    };
+    let sub = Subscription {
+        fan: fan.clone(),
+        plan_id: 7,
+        expiry: 100_000,
+    };
+    let batch = vec![
+        &env,
+        MigrationEntry::Plan(7, plan.clone()),
+        MigrationEntry::Subscription(creator.clone(), sub),
+    ];
+    let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+
+    client.begin_migration();
+    client.import_subscriptions(&batch, &checksum);
+    client.finalize_migration();
+
+    assert!(client.is_subscriber(&fan, &creator));
+    assert_eq!(client.creator_subscription_count(&creator), 1);
+    assert_eq!(client.plan_stats(&7).active, 1);
+    let state = client.migration_state();
+    assert!(state.finalized && !state.active);
+    assert_eq!(state.imported, 2);
+    assert_eq!(state.checksum, checksum);
+    // New plans continue after the highest imported ID.
+    assert_eq!(client.create_plan(&creator, &token.address, &500, &30), 8);
+}
+
+#[test]
+fn test_import_restores_plan_currency_and_tier() {
+    let (env, client, token, _oracle, creator, fan, _) = setup_usd_plan();
+    let plan = Plan {
+        creator: creator.clone(),
+        asset: token.address.clone(),
+        amount: 50_000_000,
+        interval_days: 30,
+    };
+    let batch = vec![
+        &env,
+        MigrationEntry::Plan(7, plan),
+        MigrationEntry::PlanCurrency(7, Symbol::new(&env, "USD")),
+        MigrationEntry::PlanTier(7, 2),
+    ];
+    let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+
+    client.begin_migration();
+    client.import_subscriptions(&batch, &checksum);
+    client.finalize_migration();
+
+    assert_eq!(client.plan_currency(&7), Some(Symbol::new(&env, "USD")));
+    assert_eq!(client.plan_tier(&7), 2);
+    // $5.00 at $0.25 per token, not 50_000_000 raw units.
+    assert_eq!(client.quote_plan(&7), 200_000_000);
+    let before = token.balance(&creator);
+    client.subscribe(&fan, &7, &token.address, &None, &Some(200_000_000));
+    assert_eq!(token.balance(&creator) - before, 200_000_000);
+    assert_eq!(client.subscriber_tier(&fan, &creator), Some(2));
+}
+
+#[test]
+fn test_import_rejects_currency_or_tier_for_unknown_plan() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    client.begin_migration();
+
+    for entry in [
+        MigrationEntry::PlanCurrency(9, Symbol::new(&env, "USD")),
+        MigrationEntry::PlanTier(9, 1),
+    ] {
+        let batch = vec![&env, entry];
+        let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+        assert_eq!(
+            client.try_import_subscriptions(&batch, &checksum),
+            Err(Ok(SorobanError::from_contract_error(
+                Error::PlanNotFound as u32
+            )))
+        );
+    }
+}
+
+#[test]
+fn test_import_checksum_chains_across_batches() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let entry = |fan: &Address| {
+        MigrationEntry::Subscription(
+            creator.clone(),
+            Subscription {
+                fan: fan.clone(),
+                plan_id: 0,
+                expiry: 100_000,
+            },
+        )
+    };
+    let first = vec![&env, entry(&Address::generate(&env))];
+    let second = vec![&env, entry(&Address::generate(&env))];
+    let after_first = migration_checksum(&env, zero_checksum(&env), &first);
+    let after_second = migration_checksum(&env, after_first.clone(), &second);
+
+    client.begin_migration();
+    client.import_subscriptions(&first, &after_first);
+    client.import_subscriptions(&second, &after_second);
+
+    assert_eq!(client.migration_state().imported, 2);
+    assert_eq!(client.creator_subscription_count(&creator), 2);
+}
+
+#[test]
+fn test_import_rejects_checksum_mismatch() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let fan = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let batch = vec![
+        &env,
+        MigrationEntry::Subscription(
+            creator.clone(),
+            Subscription {
+                fan: fan.clone(),
+                plan_id: 0,
+                expiry: 100_000,
+            },
+        ),
+    ];
+
+    client.begin_migration();
+    let result = client.try_import_subscriptions(&batch, &zero_checksum(&env));
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::ChecksumMismatch as u32
+        )))
+    );
+    assert!(!client.is_subscriber(&fan, &creator));
+    assert_eq!(client.migration_state().imported, 0);
+}
+
+#[test]
+fn test_import_requires_migration_mode() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let batch = vec![
+        &env,
+        MigrationEntry::Subscription(
+            Address::generate(&env),
+            Subscription {
+                fan: Address::generate(&env),
+                plan_id: 0,
+                expiry: 100_000,
+            },
+        ),
+    ];
+    let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+
+    assert_eq!(
+        client.try_import_subscriptions(&batch, &checksum),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::NotInMigration as u32
+        )))
+    );
+}
+
+#[test]
+fn test_import_rejects_oversized_batch() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let mut batch = soroban_sdk::Vec::new(&env);
+    for _ in 0..26 {
+        batch.push_back(MigrationEntry::Subscription(
+            creator.clone(),
+            Subscription {
+                fan: Address::generate(&env),
+                plan_id: 0,
+                expiry: 100_000,
+            },
+        ));
+    }
+    let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+
+    client.begin_migration();
+    assert_eq!(
+        client.try_import_subscriptions(&batch, &checksum),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::BatchTooLarge as u32
+        )))
+    );
+}
+
+#[test]
+fn test_import_rejects_subscriptions_to_unknown_or_foreign_plans() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let other = Address::generate(&env);
+    let plan = Plan {
+        creator: other.clone(),
+        asset: token.address.clone(),
+        amount: 1000,
+        interval_days: 30,
+    };
+    let entry = |plan_id: u32| {
+        MigrationEntry::Subscription(
+            creator.clone(),
+            Subscription {
+                fan: Address::generate(&env),
+                plan_id,
+                expiry: 100_000,
+            },
+        )
+    };
+
+    client.begin_migration();
+    for batch in [
+        vec![&env, entry(3)],
+        vec![&env, MigrationEntry::Plan(3, plan.clone()), entry(3)],
+    ] {
+        let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+        assert_eq!(
+            client.try_import_subscriptions(&batch, &checksum),
+            Err(Ok(SorobanError::from_contract_error(
+                Error::PlanNotFound as u32
+            )))
+        );
+    }
+    assert_eq!(client.creator_subscription_count(&creator), 0);
+}
+
+#[test]
+fn test_plan_tier_is_frozen_during_migration() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let plan_id = client.create_plan(&creator, &token.address, &1000, &30);
+
+    client.begin_migration();
+    assert_eq!(
+        client.try_set_plan_tier(&plan_id, &2),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::MigrationInProgress as u32
+        )))
+    );
+    client.finalize_migration();
+    client.set_plan_tier(&plan_id, &2);
+    assert_eq!(client.plan_tier(&plan_id), 2);
+}
+
+#[test]
+fn test_migration_blocks_normal_traffic_until_finalized() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &5000);
+
+    client.begin_migration();
+    assert_eq!(
+        client.try_create_plan(&creator, &token.address, &1000, &30),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::MigrationInProgress as u32
+        )))
+    );
+    assert_eq!(
+        client.try_create_subscription(&fan, &creator, &100),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::MigrationInProgress as u32
+        )))
+    );
+
+    client.finalize_migration();
+    client.create_subscription(&fan, &creator, &100);
+    assert!(client.is_subscriber(&fan, &creator));
+}
+
+#[test]
+fn test_finalize_migration_locks_imports_permanently() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+
+    client.begin_migration();
+    client.finalize_migration();
+
+    assert_eq!(
+        client.try_begin_migration(),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::MigrationFinalized as u32
+        )))
+    );
+    let batch = vec![
+        &env,
+        MigrationEntry::Subscription(
+            Address::generate(&env),
+            Subscription {
+                fan: Address::generate(&env),
+                plan_id: 0,
+                expiry: 100_000,
+            },
+        ),
+    ];
+    let checksum = migration_checksum(&env, zero_checksum(&env), &batch);
+    assert_eq!(
+        client.try_import_subscriptions(&batch, &checksum),
+        Err(Ok(SorobanError::from_contract_error(
+            Error::MigrationFinalized as u32
+        )))
+    );
+}
+
+// ── Plan tiers ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_subscriber_tier_follows_active_plan() {
+    let (env, client, admin, token, token_admin) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+    let creator = Address::generate(&env);
+    let fan = Address::generate(&env);
+    token_admin.mint(&fan, &10_000);
+
+    let basic = client.create_plan(&creator, &token.address, &1000, &30);
+    let premium = client.create_plan(&creator, &token.address, &2000, &30);
+    client.set_plan_tier(&premium, &2);
+    assert_eq!(client.plan_tier(&basic), 0);
+    assert_eq!(client.plan_tier(&premium), 2);
+
+    assert_eq!(client.subscriber_tier(&fan, &creator), None);
+    client.subscribe(&fan, &basic, &token.address, &None, &None);
+    assert_eq!(client.subscriber_tier(&fan, &creator), Some(0));
+    client.subscribe(&fan, &premium, &token.address, &None, &None);
+    assert_eq!(client.subscriber_tier(&fan, &creator), Some(2));
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number += 30 * 17_280 + 1);
+    assert_eq!(client.subscriber_tier(&fan, &creator), None);
+}
+
+#[test]
+fn test_set_plan_tier_unknown_plan_fails() {
+    let (env, client, admin, token, _) = setup_test();
+    let fee_recipient = Address::generate(&env);
+    client.init(&admin, &0, &fee_recipient, &token.address, &1000);
+
+    let result = client.try_set_plan_tier(&99, &1);
+    assert_eq!(
+        result,
+        Err(Ok(SorobanError::from_contract_error(
+            Error::PlanNotFound as u32
+        )))
+    );
+}
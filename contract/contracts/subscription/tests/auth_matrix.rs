@@ -24,7 +24,7 @@ use subscription::{MyfansContract, MyfansContractClient};
 
 const EMPTY_AUTHS: &[SorobanAuthorizationEntry] = &[];
 
-const NO_EXPIRY: u64 = u64::MAX;
+const LIFETIME: u32 = 0;
 
 fn base_env() -> Env {
     let env = Env::default();
@@ -513,7 +513,7 @@ fn sub_subscribe_valid_fan_signs() {
     let env = base_env();
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-    sub.subscribe(&fan, &plan_id, &token.address);
+    sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert!(sub.is_subscriber(&fan, &creator));
 }
 
@@ -524,7 +524,7 @@ fn sub_subscribe_invalid_third_party_rejected() {
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
     env.set_auths(EMPTY_AUTHS);
-    let result = sub.try_subscribe(&fan, &plan_id, &token.address);
+    let result = sub.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
     assert!(result.is_err(), "third party must not subscribe as fan");
 }
 
@@ -546,9 +546,9 @@ fn sub_extend_subscription_valid_fan_signs() {
     let env = base_env();
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-    sub.subscribe(&fan, &plan_id, &token.address);
+    sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
     token.mint(&fan, &10_000i128);
-    sub.extend_subscription(&fan, &creator, &100u32, &token.address);
+    sub.extend_subscription(&fan, &creator, &100u32, &token.address, &None);
     assert!(sub.is_subscriber(&fan, &creator));
 }
 
@@ -558,9 +558,9 @@ fn sub_extend_subscription_invalid_third_party_rejected() {
     let env = base_env();
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-    sub.subscribe(&fan, &plan_id, &token.address);
+    sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
     env.set_auths(EMPTY_AUTHS);
-    let result = sub.try_extend_subscription(&fan, &creator, &100u32, &token.address);
+    let result = sub.try_extend_subscription(&fan, &creator, &100u32, &token.address, &None);
     assert!(
         result.is_err(),
         "third party must not extend fan subscription"
@@ -573,7 +573,7 @@ fn sub_cancel_valid_fan_signs() {
     let env = base_env();
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-    sub.subscribe(&fan, &plan_id, &token.address);
+    sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
     sub.cancel(&fan, &creator, &0u32);
     assert!(!sub.is_subscriber(&fan, &creator));
 }
@@ -584,7 +584,7 @@ fn sub_cancel_invalid_creator_cannot_cancel_fan() {
     let env = base_env();
     let (sub, token, _admin, creator, fan) = sub_setup(&env);
     let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-    sub.subscribe(&fan, &plan_id, &token.address);
+    sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
     env.set_auths(EMPTY_AUTHS);
     let result = sub.try_cancel(&fan, &creator, &0u32);
     assert!(result.is_err(), "creator must not cancel fan subscription");
@@ -722,7 +722,7 @@ fn content_unlock_valid_buyer_signs() {
     let env = base_env();
     let (content, _token, _admin, buyer, creator) = content_setup(&env);
     content.set_content_price(&creator, &1u64, &500i128);
-    content.unlock_content(&buyer, &creator, &1u64, &NO_EXPIRY);
+    content.unlock_content(&buyer, &creator, &1u64, &LIFETIME);
     assert!(content.has_access(&buyer, &creator, &1u64));
 }
 
@@ -733,7 +733,7 @@ fn content_unlock_invalid_third_party_rejected() {
     let (content, _token, _admin, buyer, creator) = content_setup(&env);
     content.set_content_price(&creator, &1u64, &500i128);
     env.set_auths(EMPTY_AUTHS);
-    let result = content.try_unlock_content(&buyer, &creator, &1u64, &NO_EXPIRY);
+    let result = content.try_unlock_content(&buyer, &creator, &1u64, &LIFETIME);
     assert!(
         result.is_err(),
         "third party must not unlock on behalf of buyer"
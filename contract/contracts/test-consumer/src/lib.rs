@@ -637,6 +637,47 @@ mod test {
             );
             assert_eq!(SubError::InvalidPrice as u32, sub_err::INVALID_PRICE);
             assert_eq!(SubError::PlanNotFound as u32, sub_err::PLAN_NOT_FOUND);
+            assert_eq!(
+                SubError::InvalidPlanParams as u32,
+                sub_err::INVALID_PLAN_PARAMS
+            );
+            assert_eq!(SubError::InvalidAmount as u32, sub_err::INVALID_AMOUNT);
+            assert_eq!(SubError::SelfReferral as u32, sub_err::SELF_REFERRAL);
+            assert_eq!(
+                SubError::CircularReferral as u32,
+                sub_err::CIRCULAR_REFERRAL
+            );
+            assert_eq!(
+                SubError::OracleNotConfigured as u32,
+                sub_err::ORACLE_NOT_CONFIGURED
+            );
+            assert_eq!(
+                SubError::OraclePriceUnavailable as u32,
+                sub_err::ORACLE_PRICE_UNAVAILABLE
+            );
+            assert_eq!(SubError::StalePrice as u32, sub_err::STALE_PRICE);
+            assert_eq!(
+                SubError::SlippageExceeded as u32,
+                sub_err::SLIPPAGE_EXCEEDED
+            );
+            assert_eq!(
+                SubError::MigrationInProgress as u32,
+                sub_err::MIGRATION_IN_PROGRESS
+            );
+            assert_eq!(SubError::NotInMigration as u32, sub_err::NOT_IN_MIGRATION);
+            assert_eq!(
+                SubError::MigrationFinalized as u32,
+                sub_err::MIGRATION_FINALIZED
+            );
+            assert_eq!(
+                SubError::ChecksumMismatch as u32,
+                sub_err::CHECKSUM_MISMATCH
+            );
+            assert_eq!(SubError::BatchTooLarge as u32, sub_err::BATCH_TOO_LARGE);
+            assert_eq!(
+                SubError::MaxAmountRequired as u32,
+                sub_err::MAX_AMOUNT_REQUIRED
+            );
         }
 
         /// End-to-end: create plan → subscribe → verify balance and active state.
@@ -660,7 +701,7 @@ mod test {
             let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
             assert_eq!(plan_id, 1u32, "first plan should have id 1");
 
-            sub.subscribe(&fan, &plan_id, &token.address);
+            sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
 
             // 5% fee on 1000
             assert_eq!(token.balance(&fan), 4_000i128);
@@ -687,7 +728,7 @@ mod test {
             let sub = deploy_subscription(&env, &admin, &fee_recipient, &token.address);
             let fan = Address::generate(&env);
 
-            let result = sub.try_subscribe(&fan, &9999u32, &token.address);
+            let result = sub.try_subscribe(&fan, &9999u32, &token.address, &None, &None);
             assert_eq!(
                 result,
                 Err(Ok(SorobanError::from_contract_error(
@@ -718,7 +759,7 @@ mod test {
             let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
             sub.pause();
 
-            let result = sub.try_subscribe(&fan, &plan_id, &token.address);
+            let result = sub.try_subscribe(&fan, &plan_id, &token.address, &None, &None);
             assert_eq!(
                 result,
                 Err(Ok(SorobanError::from_contract_error(sub_err::PAUSED))),
@@ -745,7 +786,7 @@ mod test {
             token.mint(&fan, &5_000i128);
 
             let plan_id = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
-            sub.subscribe(&fan, &plan_id, &token.address);
+            sub.subscribe(&fan, &plan_id, &token.address, &None, &None);
             assert!(sub.is_subscriber(&fan, &creator));
 
             sub.cancel(&fan, &creator, &0u32);
@@ -759,12 +800,58 @@ mod test {
                 "expiry must be zeroed after cancel"
             );
         }
+
+        /// content-access honours subscriber-included content by calling the
+        /// real subscription contract, enforcing the minimum plan tier.
+        #[test]
+        fn subscription_unlocks_tier_gated_content() {
+            let env = Env::default();
+            env.mock_all_auths();
+            env.ledger().with_mut(|li| {
+                li.min_persistent_entry_ttl = 10_000_000;
+                li.min_temp_entry_ttl = 10_000_000;
+            });
+
+            let (token, admin) = deploy_token(&env);
+            let fee_recipient = Address::generate(&env);
+            let sub = deploy_subscription(&env, &admin, &fee_recipient, &token.address);
+            let content = content_access::ContentAccessClient::new(
+                &env,
+                &env.register_contract(None, content_access::ContentAccess),
+            );
+            content.initialize(&admin, &token.address);
+            content.set_subscription_contract(&sub.address);
+
+            let creator = Address::generate(&env);
+            let fan = Address::generate(&env);
+            token.mint(&fan, &5_000i128);
+
+            let basic = sub.create_plan(&creator, &token.address, &1000i128, &30u32);
+            let premium = sub.create_plan(&creator, &token.address, &2000i128, &30u32);
+            sub.set_plan_tier(&premium, &1u32);
+            content.include_for_subscribers(&creator, &1u64, &None);
+            content.include_for_subscribers(&creator, &2u64, &Some(1u32));
+
+            sub.subscribe(&fan, &basic, &token.address, &None, &None);
+            assert!(content.has_access(&fan, &creator, &1u64));
+            assert!(!content.has_access(&fan, &creator, &2u64));
+
+            sub.subscribe(&fan, &premium, &token.address, &None, &None);
+            assert!(content.has_access(&fan, &creator, &2u64));
+
+            sub.cancel(&fan, &creator, &0u32);
+            assert!(!content.has_access(&fan, &creator, &1u64));
+        }
     }
 
     // ── content-access integration (Issue #XXXX) ────────────────────────────────
 
     mod content_access_integration {
-        use content_access::{ContentAccess, ContentAccessClient};
+        use content_access::{
+            AttestationError, ContentAccess, ContentAccessClient, Error as AccessError,
+            PreOrderError,
+        };
+        use myfans_lib::error_codes::content_access as access_err;
         use soroban_sdk::{
             contract, contractimpl,
             testutils::{Address as _, Ledger},
@@ -803,6 +890,208 @@ mod test {
             client
         }
 
+        /// content-access contract error discriminants must match the stable
+        /// constants published in `myfans_lib::error_codes::content_access`.
+        #[test]
+        fn content_access_error_codes_match_stable_constants() {
+            assert_eq!(
+                AccessError::AlreadyInitialized as u32,
+                access_err::ALREADY_INITIALIZED
+            );
+            assert_eq!(
+                AccessError::ContentPriceNotSet as u32,
+                access_err::CONTENT_PRICE_NOT_SET
+            );
+            assert_eq!(
+                AccessError::NotInitialized as u32,
+                access_err::NOT_INITIALIZED
+            );
+            assert_eq!(
+                AccessError::PurchaseExpired as u32,
+                access_err::PURCHASE_EXPIRED
+            );
+            assert_eq!(AccessError::NotBuyer as u32, access_err::NOT_BUYER);
+            assert_eq!(AccessError::InvalidPrice as u32, access_err::INVALID_PRICE);
+            assert_eq!(
+                AccessError::PriceExceedsMax as u32,
+                access_err::PRICE_EXCEEDS_MAX
+            );
+            assert_eq!(
+                AccessError::InvalidMaxPrice as u32,
+                access_err::INVALID_MAX_PRICE
+            );
+            assert_eq!(AccessError::Paused as u32, access_err::PAUSED);
+            assert_eq!(
+                AccessError::InvalidExpiry as u32,
+                access_err::INVALID_EXPIRY
+            );
+            assert_eq!(
+                AccessError::ContentInactive as u32,
+                access_err::CONTENT_INACTIVE
+            );
+            assert_eq!(
+                AccessError::ContentAlreadyRegistered as u32,
+                access_err::CONTENT_ALREADY_REGISTERED
+            );
+            assert_eq!(
+                AccessError::ContentNotFound as u32,
+                access_err::CONTENT_NOT_FOUND
+            );
+            assert_eq!(
+                AccessError::AccessOptionNotFound as u32,
+                access_err::ACCESS_OPTION_NOT_FOUND
+            );
+            assert_eq!(
+                AccessError::TooManyAccessOptions as u32,
+                access_err::TOO_MANY_ACCESS_OPTIONS
+            );
+            assert_eq!(
+                AccessError::BundleNotFound as u32,
+                access_err::BUNDLE_NOT_FOUND
+            );
+            assert_eq!(
+                AccessError::InvalidBundle as u32,
+                access_err::INVALID_BUNDLE
+            );
+            assert_eq!(
+                AccessError::InvalidFeeRecipient as u32,
+                access_err::INVALID_FEE_RECIPIENT
+            );
+            assert_eq!(
+                AccessError::InvalidFeeBps as u32,
+                access_err::INVALID_FEE_BPS
+            );
+            assert_eq!(
+                AccessError::VoucherKeyNotSet as u32,
+                access_err::VOUCHER_KEY_NOT_SET
+            );
+            assert_eq!(
+                AccessError::VoucherExpired as u32,
+                access_err::VOUCHER_EXPIRED
+            );
+            assert_eq!(
+                AccessError::VoucherExhausted as u32,
+                access_err::VOUCHER_EXHAUSTED
+            );
+            assert_eq!(
+                AccessError::VoucherNotForBuyer as u32,
+                access_err::VOUCHER_NOT_FOR_BUYER
+            );
+            assert_eq!(
+                AccessError::NotRefundable as u32,
+                access_err::NOT_REFUNDABLE
+            );
+            assert_eq!(
+                AccessError::RefundWindowClosed as u32,
+                access_err::REFUND_WINDOW_CLOSED
+            );
+            assert_eq!(AccessError::NotRefunder as u32, access_err::NOT_REFUNDER);
+            assert_eq!(
+                AccessError::AlreadyHasAccess as u32,
+                access_err::ALREADY_HAS_ACCESS
+            );
+            assert_eq!(
+                AccessError::ContentModerated as u32,
+                access_err::CONTENT_MODERATED
+            );
+            assert_eq!(AccessError::InvalidCart as u32, access_err::INVALID_CART);
+            assert_eq!(
+                AccessError::NotYetReleased as u32,
+                access_err::NOT_YET_RELEASED
+            );
+            assert_eq!(
+                AccessError::AlreadyReleased as u32,
+                access_err::ALREADY_RELEASED
+            );
+            assert_eq!(
+                AccessError::InvalidReleaseLedger as u32,
+                access_err::INVALID_RELEASE_LEDGER
+            );
+            assert_eq!(
+                AccessError::DelegationLimitReached as u32,
+                access_err::DELEGATION_LIMIT_REACHED
+            );
+            assert_eq!(
+                AccessError::DelegationCooldown as u32,
+                access_err::DELEGATION_COOLDOWN
+            );
+            assert_eq!(
+                AccessError::AlreadyDelegated as u32,
+                access_err::ALREADY_DELEGATED
+            );
+            assert_eq!(AccessError::SoldOut as u32, access_err::SOLD_OUT);
+            assert_eq!(
+                AccessError::InvalidSupplyCap as u32,
+                access_err::INVALID_SUPPLY_CAP
+            );
+            assert_eq!(
+                AccessError::ResaleDisabled as u32,
+                access_err::RESALE_DISABLED
+            );
+            assert_eq!(
+                AccessError::NotResellable as u32,
+                access_err::NOT_RESELLABLE
+            );
+            assert_eq!(
+                AccessError::ListingNotFound as u32,
+                access_err::LISTING_NOT_FOUND
+            );
+            assert_eq!(
+                AccessError::PriceBelowMin as u32,
+                access_err::PRICE_BELOW_MIN
+            );
+            assert_eq!(
+                AccessError::PriceExceedsCategoryMax as u32,
+                access_err::PRICE_EXCEEDS_CATEGORY_MAX
+            );
+            assert_eq!(
+                AccessError::PriceBelowCategoryMin as u32,
+                access_err::PRICE_BELOW_CATEGORY_MIN
+            );
+            assert_eq!(
+                AccessError::PriceExceedsTrustMax as u32,
+                access_err::PRICE_EXCEEDS_TRUST_MAX
+            );
+            assert_eq!(
+                AccessError::PriceBelowTrustMin as u32,
+                access_err::PRICE_BELOW_TRUST_MIN
+            );
+            assert_eq!(
+                AccessError::InvalidPriceRule as u32,
+                access_err::INVALID_PRICE_RULE
+            );
+            assert_eq!(
+                AccessError::AmountBelowPrice as u32,
+                access_err::AMOUNT_BELOW_PRICE
+            );
+            assert_eq!(AccessError::TipsDisabled as u32, access_err::TIPS_DISABLED);
+            assert_eq!(AccessError::InvalidSale as u32, access_err::INVALID_SALE);
+            assert_eq!(
+                AccessError::CreatorNotRegistered as u32,
+                access_err::CREATOR_NOT_REGISTERED
+            );
+            assert_eq!(
+                AttestationError::AttestorNotApproved as u32,
+                access_err::ATTESTOR_NOT_APPROVED
+            );
+            assert_eq!(
+                AttestationError::AttestationExpired as u32,
+                access_err::ATTESTATION_EXPIRED
+            );
+            assert_eq!(
+                AttestationError::AttestationRequired as u32,
+                access_err::ATTESTATION_REQUIRED
+            );
+            assert_eq!(
+                PreOrderError::EscrowPending as u32,
+                access_err::ESCROW_PENDING
+            );
+            assert_eq!(
+                PreOrderError::RefundsPending as u32,
+                access_err::REFUNDS_PENDING
+            );
+        }
+
         #[test]
         fn content_access_basic_flow() {
             let env = Env::default();
@@ -834,7 +1123,7 @@ mod test {
             );
 
             // Buyer unlocks content
-            content_access.unlock_content(&buyer, &creator, &content_id, &2000); // expiry far in future
+            content_access.unlock_content(&buyer, &creator, &content_id, &0); // lifetime option
 
             // Verify access is granted
             assert!(content_access.has_access(&buyer, &creator, &content_id));
@@ -877,10 +1166,20 @@ mod test {
             let creator = Address::generate(&env);
             let content_id = 1u64;
 
-            content_access.set_content_price(&creator, &content_id, &50);
+            content_access.set_access_options(
+                &creator,
+                &content_id,
+                &soroban_sdk::vec![
+                    &env,
+                    content_access::AccessOption {
+                        duration_ledgers: Some(5),
+                        price: 50,
+                    },
+                ],
+            );
 
-            // Purchase with near expiry
-            content_access.unlock_content(&buyer, &creator, &content_id, &1005); // expires at ledger 1005
+            // Purchase a 5-ledger rental
+            content_access.unlock_content(&buyer, &creator, &content_id, &0); // expires at ledger 1005
             assert!(content_access.has_access(&buyer, &creator, &content_id));
 
             // Advance to just before expiry
@@ -901,7 +1200,7 @@ mod test {
             );
 
             // Repurchase with new expiry
-            content_access.unlock_content(&buyer, &creator, &content_id, &2000);
+            content_access.unlock_content(&buyer, &creator, &content_id, &0);
             assert!(content_access.has_access(&buyer, &creator, &content_id));
         }
     }